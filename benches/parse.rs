@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use opensea_stream::{
+    fixtures,
+    schema::{NftId, StreamEvent, StreamEventHeader},
+};
+
+const NFT_ID: &str = r#""ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234""#;
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("stream_event_from_str", |b| {
+        b.iter(|| {
+            for fixture in fixtures::ALL {
+                serde_json::from_str::<StreamEvent>(black_box(fixture)).unwrap();
+            }
+        })
+    });
+
+    // phyllo decodes payloads from a `Value`, not directly from the frame.
+    let values: Vec<serde_json::Value> = fixtures::ALL
+        .iter()
+        .map(|fixture| serde_json::from_str(fixture).unwrap())
+        .collect();
+    c.bench_function("stream_event_from_value", |b| {
+        b.iter(|| {
+            for value in &values {
+                serde_json::from_value::<StreamEvent>(black_box(value.clone())).unwrap();
+            }
+        })
+    });
+
+    c.bench_function("stream_event_header_from_value", |b| {
+        b.iter(|| {
+            for value in &values {
+                serde_json::from_value::<StreamEventHeader>(black_box(value.clone())).unwrap();
+            }
+        })
+    });
+
+    c.bench_function("nft_id_from_str", |b| {
+        b.iter(|| serde_json::from_str::<NftId>(black_box(NFT_ID)).unwrap())
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+#![no_main]
+
+//! Replaces values inside the fixtures with arbitrary JSON, so that the fuzzer
+//! spends its time in the field parsers rather than failing on the outer shape.
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use opensea_stream::{fixtures, schema::StreamEvent};
+use serde_json::{Map, Number, Value};
+
+#[derive(Arbitrary, Debug)]
+enum Json {
+    Null,
+    Bool(bool),
+    Float(f64),
+    Int(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl From<Json> for Value {
+    fn from(json: Json) -> Self {
+        match json {
+            Json::Null => Value::Null,
+            Json::Bool(b) => Value::Bool(b),
+            Json::Float(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+            Json::Int(i) => Value::Number(i.into()),
+            Json::String(s) => Value::String(s),
+            Json::Array(a) => Value::Array(a.into_iter().map(Into::into).collect()),
+            Json::Object(o) => Value::Object(
+                o.into_iter()
+                    .map(|(k, v)| (k, v.into()))
+                    .collect::<Map<_, _>>(),
+            ),
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct Mutation {
+    /// Indices used to pick a child at each level of nesting.
+    path: Vec<u8>,
+    value: Json,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    fixture: u8,
+    mutations: Vec<Mutation>,
+}
+
+fn target<'a>(value: &'a mut Value, path: &[u8]) -> &'a mut Value {
+    let (first, rest) = match path.split_first() {
+        Some(v) => v,
+        None => return value,
+    };
+
+    let len = match &*value {
+        Value::Object(map) => map.len(),
+        Value::Array(array) => array.len(),
+        _ => 0,
+    };
+    if len == 0 {
+        return value;
+    }
+
+    let i = *first as usize % len;
+    match value {
+        Value::Object(map) => target(map.values_mut().nth(i).unwrap(), rest),
+        Value::Array(array) => target(&mut array[i], rest),
+        _ => unreachable!(),
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let fixture = fixtures::ALL[input.fixture as usize % fixtures::ALL.len()];
+    let mut value: Value = serde_json::from_str(fixture).unwrap();
+
+    for mutation in input.mutations {
+        *target(&mut value, &mutation.path) = mutation.value.into();
+    }
+
+    // phyllo decodes payloads from a `Value`, so exercise both paths.
+    let _ = serde_json::from_str::<StreamEvent>(&value.to_string());
+    let _ = serde_json::from_value::<StreamEvent>(value);
+});
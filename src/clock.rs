@@ -0,0 +1,58 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::{Arc, Mutex};
+
+/// Source of the current time.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock, i.e. [`Utc::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock which only moves when told to.
+///
+/// Clones share the same time, so a clone can be kept to advance the time of
+/// a clock that has been handed to a [`Filter`](crate::filter::Filter).
+/// ```
+/// # use chrono::Duration;
+/// # use opensea_stream::clock::{Clock, MockClock};
+/// let clock = MockClock::new("2022-09-12T00:00:00Z".parse()?);
+/// let handle = clock.clone();
+///
+/// handle.advance(Duration::seconds(30));
+/// assert_eq!(clock.now().to_rfc3339(), "2022-09-12T00:00:30+00:00");
+/// # Ok::<(), chrono::ParseError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl MockClock {
+    /// Constructs a new `MockClock` set to `now`.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self(Arc::new(Mutex::new(now)))
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.0.lock().unwrap() = now;
+    }
+
+    /// Moves the current time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
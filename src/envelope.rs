@@ -0,0 +1,121 @@
+use crate::{
+    clock::{Clock, SystemClock},
+    schema::StreamEvent,
+    Collection, Event,
+};
+use chrono::{DateTime, Utc};
+use phyllo::message::{Message, Payload};
+use serde_json::Value;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// An event along with metadata about its receipt, from an [`EnvelopeReceiver`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Envelope<R = StreamEvent> {
+    /// Local timestamp of when the event was taken from the subscription by
+    /// [`EnvelopeReceiver::recv`].
+    ///
+    /// This is not when the event arrived on the socket: if the receiver falls behind,
+    /// events wait in the subscription's buffer and are stamped when they are dequeued.
+    pub received_at: DateTime<Utc>,
+    /// Topic the event was received on.
+    pub topic: Collection,
+    /// Position of the event in the subscription, starting from `0`.
+    ///
+    /// Events missed by lagging behind are counted too, so a gap in `seq` means events were lost.
+    pub seq: u64,
+    /// Number of events missed immediately before this one by lagging behind.
+    pub missed: u64,
+    /// The event itself.
+    pub event: R,
+}
+
+/// Wraps a subscription to deliver [`Envelope`]s instead of raw Phoenix messages.
+///
+/// Messages without a payload are skipped. Unlike the wrapped receiver, lagging behind is
+/// not an error; the number of events missed is reported in the next [`Envelope`] instead,
+/// e.g. to trigger a re-sync from the REST API.
+/// ```no_run
+/// # use opensea_stream::{client, envelope::EnvelopeReceiver, subscribe_to, Collection, Network};
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+/// let (_handler, subscription) = subscribe_to(&client, Collection::All).await?;
+///
+/// let mut subscription = EnvelopeReceiver::new(subscription);
+/// loop {
+///     let envelope = subscription.recv().await?;
+///     if envelope.missed > 0 {
+///         eprintln!("missed {} events", envelope.missed);
+///     }
+///     println!("#{} at {}: {:?}", envelope.seq, envelope.received_at, envelope.event);
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EnvelopeReceiver<R = StreamEvent, C = SystemClock> {
+    receiver: broadcast::Receiver<Message<Collection, Event, Value, R>>,
+    seq: u64,
+    missed: u64,
+    clock: C,
+}
+
+impl<R: Clone> EnvelopeReceiver<R> {
+    /// Constructs a new `EnvelopeReceiver` wrapping `receiver`.
+    pub fn new(receiver: broadcast::Receiver<Message<Collection, Event, Value, R>>) -> Self {
+        Self {
+            receiver,
+            seq: 0,
+            missed: 0,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<R: Clone, C: Clock> EnvelopeReceiver<R, C> {
+    /// Sets the clock used for `received_at`.
+    pub fn clock<T: Clock>(self, clock: T) -> EnvelopeReceiver<R, T> {
+        EnvelopeReceiver {
+            receiver: self.receiver,
+            seq: self.seq,
+            missed: self.missed,
+            clock,
+        }
+    }
+
+    /// Receives the next event.
+    ///
+    /// An error is only returned once the subscription is closed.
+    pub async fn recv(&mut self) -> Result<Envelope<R>, RecvError> {
+        let mut missed = 0;
+        loop {
+            let message = match self.receiver.recv().await {
+                Ok(message) => message,
+                Err(RecvError::Lagged(n)) => {
+                    missed += n;
+                    self.seq += n;
+                    self.missed += n;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let received_at = self.clock.now();
+            if let Some(Payload::Custom(event)) = message.payload {
+                let seq = self.seq;
+                self.seq += 1;
+                return Ok(Envelope {
+                    received_at,
+                    topic: message.topic,
+                    seq,
+                    missed,
+                    event,
+                });
+            }
+        }
+    }
+
+    /// Total number of events missed so far by lagging behind.
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{
+    clock::{Clock, SystemClock},
+    schema::{Chain, Payload, StreamEvent, StreamEventHeader},
+    Event, EventSet,
+};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+/// Filter for [`StreamEvent`]s, applied after they are received.
+///
+/// Events that do not pass the filter are counted in [`FilterStats`].
+/// ```
+/// # use opensea_stream::filter::Filter;
+/// # use std::time::Duration;
+/// let mut filter = Filter::new().max_age(Duration::from_secs(30));
+/// # let events: Vec<opensea_stream::schema::StreamEvent> = vec![];
+///
+/// for event in events {
+///     if !filter.matches(&event) {
+///         continue;
+///     }
+///     // ...
+/// }
+///
+/// println!("dropped {} stale events", filter.stats().stale);
+/// ```
+///
+/// Staleness is measured with the [`Clock`] set with [`Filter::clock`], which is the system clock by default.
+#[derive(Debug, Clone, Default)]
+pub struct Filter<C = SystemClock> {
+    max_age: Option<Duration>,
+    events: Option<EventSet>,
+    chains: Option<HashSet<Chain>>,
+    collections: Option<HashSet<String>>,
+    excluded_collections: HashSet<String>,
+    strict_chain: bool,
+    exclude_zero_value: bool,
+    stats: FilterStats,
+    clock: C,
+}
+
+/// Number of events rejected by a [`Filter`], by reason.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct FilterStats {
+    /// Events older than the maximum age.
+    pub stale: u64,
+    /// Events whose type was not in the set of wanted events.
+    pub event_type: u64,
+    /// Events on chains that were not wanted, per chain.
+    pub chain: HashMap<Chain, u64>,
+    /// Events from collections that were not allowed or were excluded.
+    pub collection: u64,
+    /// Events whose item chain did not match the chain of its NFT ID, with [`Filter::strict_chain`].
+    pub inconsistent_chain: u64,
+    /// Mints and sales for nothing, with [`Filter::exclude_zero_value_transfers`].
+    pub zero_value: u64,
+}
+
+impl Filter {
+    /// Constructs a new `Filter` which lets every event through.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Clock> Filter<C> {
+    /// Sets the clock used to measure the age of events.
+    /// ```
+    /// # use opensea_stream::{clock::MockClock, filter::Filter};
+    /// # use std::time::Duration;
+    /// let clock = MockClock::new("2022-09-12T00:00:00Z".parse()?);
+    /// let filter = Filter::new()
+    ///     .max_age(Duration::from_secs(30))
+    ///     .clock(clock.clone());
+    /// # Ok::<(), chrono::ParseError>(())
+    /// ```
+    pub fn clock<T: Clock>(self, clock: T) -> Filter<T> {
+        Filter {
+            max_age: self.max_age,
+            events: self.events,
+            chains: self.chains,
+            collections: self.collections,
+            excluded_collections: self.excluded_collections,
+            strict_chain: self.strict_chain,
+            exclude_zero_value: self.exclude_zero_value,
+            stats: self.stats,
+            clock,
+        }
+    }
+
+    /// Sets the maximum age of an event, measured from its `sent_at` timestamp to now.
+    ///
+    /// Events sent in the future (due to clock skew) are never considered stale.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets the events to let through. All other events are rejected.
+    pub fn events(mut self, events: EventSet) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// Sets the chains to let through. Events on all other chains are rejected.
+    /// ```
+    /// # use opensea_stream::{filter::Filter, schema::Chain};
+    /// let filter = Filter::new().chains([Chain::Ethereum, Chain::Base]);
+    /// ```
+    pub fn chains(mut self, chains: impl IntoIterator<Item = Chain>) -> Self {
+        self.chains = Some(chains.into_iter().collect());
+        self
+    }
+
+    /// Sets the collections to let through, by slug. Events from all other collections are rejected.
+    ///
+    /// This is useful when subscribed to [`Collection::All`](crate::Collection::All).
+    pub fn collections<S: Into<String>>(mut self, slugs: impl IntoIterator<Item = S>) -> Self {
+        self.collections = Some(slugs.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets collections to reject, by slug, e.g. a blocklist of known spam collections.
+    pub fn exclude_collections<S: Into<String>>(
+        mut self,
+        slugs: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.excluded_collections = slugs.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Rejects events whose item chain does not match the chain of its NFT ID.
+    ///
+    /// See [`Item::checked_chain`](crate::schema::Item::checked_chain).
+    pub fn strict_chain(mut self) -> Self {
+        self.strict_chain = true;
+        self
+    }
+
+    /// Rejects items changing hands without any payment, a common sign of spam airdrops.
+    ///
+    /// This covers mints (see [`ItemTransferredData::is_mint`](crate::schema::ItemTransferredData::is_mint)), which carry no price, and
+    /// sales for a price of zero. Legitimate free mints are rejected too.
+    pub fn exclude_zero_value_transfers(mut self) -> Self {
+        self.exclude_zero_value = true;
+        self
+    }
+
+    /// Returns whether the event passes the filter, counting it if it does not.
+    pub fn matches(&mut self, event: &StreamEvent) -> bool {
+        if !self.matches_collection(event.payload.context().collection.slug()) {
+            return false;
+        }
+
+        let item = &event.payload.context().item;
+        if self.strict_chain && item.checked_chain().is_err() {
+            self.stats.inconsistent_chain += 1;
+            return false;
+        }
+
+        if let Some(chains) = &self.chains {
+            let chain = item.chain();
+            if !chains.contains(&chain) {
+                *self.stats.chain.entry(chain).or_default() += 1;
+                return false;
+            }
+        }
+
+        if self.exclude_zero_value && is_zero_value(&event.payload) {
+            self.stats.zero_value += 1;
+            return false;
+        }
+
+        self.matches_event(&Event::from(&event.payload)) && self.matches_age(event.sent_at)
+    }
+
+    /// Returns whether the header of an event passes the filter, counting it if it does not.
+    ///
+    /// This applies the event type, collection and age rules, so events can be filtered
+    /// before they are fully decoded. Headers carry no chain, so [`Filter::chains`] and
+    /// [`Filter::strict_chain`] are not applied; use [`Filter::matches`] on the decoded
+    /// event for those.
+    /// ```
+    /// # use opensea_stream::{filter::Filter, schema::{StreamEvent, StreamEventHeader}, Event};
+    /// # fn handle(_: StreamEvent) {}
+    /// let mut filter = Filter::new().events([Event::ItemSold].into_iter().collect());
+    /// # let headers: Vec<StreamEventHeader> = vec![];
+    ///
+    /// for header in headers {
+    ///     if !filter.matches_header(&header) {
+    ///         continue;
+    ///     }
+    ///     if let Ok(event) = header.into_stream_event() {
+    ///         handle(event);
+    ///     }
+    /// }
+    /// ```
+    pub fn matches_header(&mut self, header: &StreamEventHeader) -> bool {
+        self.matches_collection(header.collection.slug())
+            && self.matches_event(&header.event_type)
+            && self.matches_age(header.sent_at)
+    }
+
+    fn matches_collection(&mut self, slug: &str) -> bool {
+        if self.excluded_collections.contains(slug)
+            || self
+                .collections
+                .as_ref()
+                .is_some_and(|collections| !collections.contains(slug))
+        {
+            self.stats.collection += 1;
+            return false;
+        }
+        true
+    }
+
+    fn matches_event(&mut self, event: &Event) -> bool {
+        if let Some(events) = self.events {
+            if !events.contains(event) {
+                self.stats.event_type += 1;
+                return false;
+            }
+        }
+        true
+    }
+
+    fn matches_age(&mut self, sent_at: DateTime<Utc>) -> bool {
+        if let Some(max_age) = self.max_age {
+            let age = (self.clock.now() - sent_at).to_std().unwrap_or_default();
+            if age > max_age {
+                self.stats.stale += 1;
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Number of events rejected so far.
+    pub fn stats(&self) -> &FilterStats {
+        &self.stats
+    }
+
+    /// Resets the counters of rejected events.
+    pub fn reset_stats(&mut self) {
+        self.stats = FilterStats::default();
+    }
+}
+
+/// Whether an item changed hands without any payment.
+fn is_zero_value(payload: &Payload) -> bool {
+    match payload {
+        Payload::ItemTransferred(transfer) => transfer.is_mint(),
+        Payload::ItemSold(sale) => sale.sale_price.is_zero(),
+        _ => false,
+    }
+}
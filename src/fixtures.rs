@@ -0,0 +1,409 @@
+/// An [`ItemListed`](crate::schema::Payload::ItemListed) event on Ethereum.
+pub const ITEM_LISTED: &str = r#"{
+    "event_type": "item_listed",
+    "sent_at": "2022-09-12T08:26:47.105386+00:00",
+    "payload": {
+        "base_price": "120000000000000000",
+        "collection": { "slug": "wandernauts" },
+        "event_timestamp": "2022-09-12T08:26:46.976364+00:00",
+        "expiration_date": "2022-10-12T08:26:26.000000+00:00",
+        "is_private": false,
+        "item": {
+            "chain": { "name": "ethereum" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://i.seadn.io/gae/wandernaut-1234.png",
+                "metadata_url": "https://example.com/metadata/1234",
+                "name": "Wandernaut #1234",
+                "description": null
+            },
+            "nft_id": "ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234",
+            "permalink": "https://opensea.io/assets/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234"
+        },
+        "listing_date": "2022-09-12T08:26:26.000000+00:00",
+        "listing_type": null,
+        "maker": { "address": "0xdaeeb975729fae923d5a4fd12aabfe228f219e9c" },
+        "payment_token": {
+            "address": "0x0000000000000000000000000000000000000000",
+            "decimals": 18,
+            "eth_price": "1.000000000000000",
+            "name": "Ether",
+            "symbol": "ETH",
+            "usd_price": "1717.359999999999900000"
+        },
+        "quantity": 1,
+        "taker": null
+    }
+}"#;
+
+/// An [`ItemSold`](crate::schema::Payload::ItemSold) event on Ethereum.
+pub const ITEM_SOLD: &str = r#"{
+    "event_type": "item_sold",
+    "sent_at": "2022-09-12T09:02:13.502193+00:00",
+    "payload": {
+        "closing_date": "2022-09-12T09:01:59.000000+00:00",
+        "collection": { "slug": "wandernauts" },
+        "event_timestamp": "2022-09-12T09:01:59.000000+00:00",
+        "is_private": false,
+        "item": {
+            "chain": { "name": "ethereum" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://i.seadn.io/gae/wandernaut-1234.png",
+                "metadata_url": "https://example.com/metadata/1234",
+                "name": "Wandernaut #1234",
+                "description": null
+            },
+            "nft_id": "ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234",
+            "permalink": "https://opensea.io/assets/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234"
+        },
+        "listing_type": null,
+        "maker": { "address": "0xdaeeb975729fae923d5a4fd12aabfe228f219e9c" },
+        "payment_token": {
+            "address": "0x0000000000000000000000000000000000000000",
+            "decimals": 18,
+            "eth_price": "1.000000000000000",
+            "name": "Ether",
+            "symbol": "ETH",
+            "usd_price": "1717.359999999999900000"
+        },
+        "quantity": 1,
+        "sale_price": "120000000000000000",
+        "taker": { "address": "0xb0eb53f16947ccf25ec84d8dbc74254770f58904" },
+        "transaction": {
+            "hash": "0xbca3a0aac36098b2cc2bd818319478da6bd0c621de49f145fda9988c79fc3552",
+            "timestamp": "2022-09-12T09:01:59.000000+00:00"
+        }
+    }
+}"#;
+
+/// An [`ItemTransferred`](crate::schema::Payload::ItemTransferred) event on Polygon.
+pub const ITEM_TRANSFERRED: &str = r#"{
+    "event_type": "item_transferred",
+    "sent_at": "2022-09-12T10:15:41.830042+00:00",
+    "payload": {
+        "collection": { "slug": "sandbox-wearables" },
+        "event_timestamp": "2022-09-12T10:15:31.000000+00:00",
+        "from_account": { "address": "0xdba41ecccc3fc1626e53a13043b026c48bbf33fe" },
+        "item": {
+            "chain": { "name": "matic" },
+            "metadata": {
+                "animation_url": "https://example.com/animation/77.glb",
+                "image_url": "https://i.seadn.io/gae/wearable-77.png",
+                "metadata_url": "https://example.com/metadata/77",
+                "name": "Explorer Backpack",
+                "description": "A sturdy backpack for long journeys."
+            },
+            "nft_id": "matic/0xff9243a8f506b40928b5b7a767c76fb008f86beb/77",
+            "permalink": "https://opensea.io/assets/matic/0xff9243a8f506b40928b5b7a767c76fb008f86beb/77"
+        },
+        "quantity": 3,
+        "to_account": { "address": "0xb2737f6a6f0fb23c6f5da2cec255404e4fb44003" },
+        "transaction": {
+            "hash": "0x6f7eaed46725a2a7b860dcd6c8a1f8b46287cced9041dff02cee737443e21047",
+            "timestamp": "2022-09-12T10:15:31.000000+00:00"
+        }
+    }
+}"#;
+
+/// An [`ItemMetadataUpdated`](crate::schema::Payload::ItemMetadataUpdated) event on Klaytn.
+pub const ITEM_METADATA_UPDATED: &str = r##"{
+    "event_type": "item_metadata_updated",
+    "sent_at": "2022-09-12T11:40:05.112871+00:00",
+    "payload": {
+        "animation_url": null,
+        "background_color": "#1b1f3a",
+        "collection": { "slug": "klay-critters" },
+        "description": "Critter #5 has been revealed.",
+        "external_url": null,
+        "image_preview_url": "https://i.seadn.io/gae/critter-5.png?w=500",
+        "image_url": "https://example.com/images/5.png",
+        "item": {
+            "chain": { "name": "klaytn" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://i.seadn.io/gae/critter-unrevealed.png",
+                "metadata_url": "https://example.com/metadata/5",
+                "name": "Critter #5",
+                "description": null
+            },
+            "nft_id": "klaytn/0x4d6608697a8d41bed440e50454f31af3176813e0/5",
+            "permalink": "https://opensea.io/assets/klaytn/0x4d6608697a8d41bed440e50454f31af3176813e0/5"
+        },
+        "metadata_url": "https://example.com/metadata/5",
+        "name": "Critter #5",
+        "traits": [
+            {
+                "trait_type": "Background",
+                "value": "Midnight",
+                "display_type": null,
+                "max_value": null,
+                "trait_count": 0,
+                "order": null
+            },
+            {
+                "trait_type": "Level",
+                "value": 3,
+                "display_type": "number",
+                "max_value": 10,
+                "trait_count": 0,
+                "order": null
+            }
+        ]
+    }
+}"##;
+
+/// An [`ItemMetadataUpdated`](crate::schema::Payload::ItemMetadataUpdated) event on Ethereum
+/// with irregular creator-supplied metadata: URLs that are empty or have no scheme,
+/// numbers encoded as strings, a fractional trait count, a list as a trait value and a `null` trait.
+pub const ITEM_METADATA_UPDATED_IRREGULAR: &str = r##"{
+    "event_type": "item_metadata_updated",
+    "sent_at": "2022-09-12T11:52:40.331190+00:00",
+    "payload": {
+        "animation_url": null,
+        "background_color": null,
+        "collection": { "slug": "wandernauts" },
+        "description": "",
+        "external_url": "www.example.com",
+        "image_preview_url": "https://i.seadn.io/gae/wandernaut-1234.png?w=500",
+        "image_url": "",
+        "item": {
+            "chain": { "name": "ethereum" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://i.seadn.io/gae/wandernaut-1234.png",
+                "metadata_url": null,
+                "name": "Wandernaut #1234",
+                "description": null
+            },
+            "nft_id": "ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234",
+            "permalink": "https://opensea.io/assets/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234"
+        },
+        "metadata_url": "",
+        "name": "Wandernaut #1234",
+        "traits": [
+            null,
+            {
+                "trait_type": "Level",
+                "value": "7",
+                "display_type": "number",
+                "max_value": "10",
+                "trait_count": 1.5,
+                "order": null
+            },
+            {
+                "trait_type": "Colors",
+                "value": ["Red", "Blue"],
+                "display_type": null,
+                "max_value": null,
+                "trait_count": 2,
+                "order": null
+            }
+        ]
+    }
+}"##;
+
+/// An [`ItemCancelled`](crate::schema::Payload::ItemCancelled) event on Goerli.
+pub const ITEM_CANCELLED: &str = r#"{
+    "event_type": "item_cancelled",
+    "sent_at": "2022-09-12T12:03:22.407716+00:00",
+    "payload": {
+        "collection": { "slug": "goerli-wandernauts" },
+        "event_timestamp": "2022-09-12T12:03:12.000000+00:00",
+        "item": {
+            "chain": { "name": "goerli" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": null,
+                "metadata_url": null,
+                "name": null,
+                "description": null
+            },
+            "nft_id": "goerli/0x2ea68ef786e4d3cea27d26934b484e73cf575dca/42",
+            "permalink": "https://testnets.opensea.io/assets/goerli/0x2ea68ef786e4d3cea27d26934b484e73cf575dca/42"
+        },
+        "listing_type": "english",
+        "payment_token": {
+            "address": "0xb4fbf271143f4fbf7b91a5ded31805e42b2208d6",
+            "decimals": 18,
+            "eth_price": "1.000000000000000",
+            "name": "Wrapped Ether",
+            "symbol": "WETH",
+            "usd_price": "1717.359999999999900000"
+        },
+        "quantity": 1,
+        "transaction": {
+            "hash": "0x1948d33296c87009e8a7f770d9106fd287db7f1adbc60926f6967e7893f57fd1",
+            "timestamp": "2022-09-12T12:03:12.000000+00:00"
+        }
+    }
+}"#;
+
+/// An [`ItemReceivedOffer`](crate::schema::Payload::ItemReceivedOffer) event on Mumbai.
+pub const ITEM_RECEIVED_OFFER: &str = r#"{
+    "event_type": "item_received_offer",
+    "sent_at": "2022-09-12T13:27:50.660413+00:00",
+    "payload": {
+        "base_price": "5000000000000000",
+        "collection": { "slug": "mumbai-test-drops" },
+        "created_date": "2022-09-12T13:27:40.000000+00:00",
+        "event_timestamp": "2022-09-12T13:27:40.000000+00:00",
+        "expiration_date": "2022-09-15T13:27:40.000000+00:00",
+        "item": {
+            "chain": { "name": "mumbai" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://example.com/images/9.png",
+                "metadata_url": "ipfs://QmPChd2hVbrJ6bfo3WBcTW4iZnpHm8TEzWkLHmLpXhF68A/9",
+                "name": "Test Drop #9",
+                "description": null
+            },
+            "nft_id": "mumbai/0xd6ba2b0aee0ca923732881584d8c4fa2815d2802/9",
+            "permalink": "https://testnets.opensea.io/assets/mumbai/0xd6ba2b0aee0ca923732881584d8c4fa2815d2802/9"
+        },
+        "maker": { "address": "0x827283e0ad84173581569969e58b081006f7e3df" },
+        "payment_token": {
+            "address": "0xa6fa4fb5f76172d178d61b04b0ecd319c5d1c0aa",
+            "decimals": 18,
+            "eth_price": "1.000000000000000",
+            "name": "Wrapped Ether",
+            "symbol": "WETH",
+            "usd_price": "1717.359999999999900000"
+        },
+        "quantity": 1,
+        "taker": null
+    }
+}"#;
+
+/// An [`ItemReceivedBid`](crate::schema::Payload::ItemReceivedBid) event on Baobab.
+pub const ITEM_RECEIVED_BID: &str = r#"{
+    "event_type": "item_received_bid",
+    "sent_at": "2022-09-12T14:51:09.291034+00:00",
+    "payload": {
+        "base_price": "25000000000000000000",
+        "collection": { "slug": "baobab-critters" },
+        "created_date": "2022-09-12T14:50:58.000000+00:00",
+        "event_timestamp": "2022-09-12T14:50:58.000000+00:00",
+        "expiration_date": "2022-09-13T14:50:58.000000+00:00",
+        "item": {
+            "chain": { "name": "baobab" },
+            "metadata": {
+                "animation_url": null,
+                "image_url": "https://example.com/images/13.png",
+                "metadata_url": "https://example.com/metadata/13",
+                "name": "Critter #13",
+                "description": null
+            },
+            "nft_id": "baobab/0xc967a64cb14028d512c9791e558e08baa7196b50/13",
+            "permalink": "https://testnets.opensea.io/assets/baobab/0xc967a64cb14028d512c9791e558e08baa7196b50/13"
+        },
+        "maker": { "address": "0xac2f86702824c1c099724caf4941d4072014b3ce" },
+        "payment_token": {
+            "address": "0x0000000000000000000000000000000000000000",
+            "decimals": 18,
+            "eth_price": "0.000151000000000000",
+            "name": "Klaytn",
+            "symbol": "KLAY",
+            "usd_price": "0.259999999999999980"
+        },
+        "quantity": 1,
+        "taker": { "address": "0x107f80e222f828767efc2f91624a8940f1f836f9" }
+    }
+}"#;
+
+/// Every fixture in this module.
+/// ```
+/// use opensea_stream::{fixtures, schema::StreamEvent};
+///
+/// for fixture in fixtures::ALL {
+///     let event: StreamEvent = serde_json::from_str(fixture)?;
+///     println!("{:?}", event.payload.context().item.nft_id);
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub const ALL: &[&str] = &[
+    ITEM_LISTED,
+    ITEM_SOLD,
+    ITEM_TRANSFERRED,
+    ITEM_METADATA_UPDATED,
+    ITEM_METADATA_UPDATED_IRREGULAR,
+    ITEM_CANCELLED,
+    ITEM_RECEIVED_OFFER,
+    ITEM_RECEIVED_BID,
+];
+
+/// Raw Phoenix channel frames, as received from the websocket.
+///
+/// Control frames such as replies are handled by phyllo and never decoded into this crate's types.
+/// Broadcasts are decoded into the same message type as [`subscribe_to`](crate::subscribe_to) returns.
+/// ```
+/// use opensea_stream::{fixtures::frames, schema::{Payload, StreamEvent}, Collection, Event};
+/// use phyllo::message::{self, Message, ProtocolEvent, PushStatus};
+/// use serde_json::Value;
+///
+/// let reply: Message<Collection, Value, Value, Value> = serde_json::from_str(frames::JOIN_REPLY)?;
+/// assert_eq!(reply.event, message::Event::Protocol(ProtocolEvent::Reply));
+/// assert!(matches!(
+///     reply.payload,
+///     Some(message::Payload::PushReply { status: PushStatus::Ok, .. })
+/// ));
+///
+/// let close: Message<Collection, Value, Value, Value> = serde_json::from_str(frames::CLOSE)?;
+/// assert_eq!(close.event, message::Event::Protocol(ProtocolEvent::Close));
+///
+/// let broadcast: Message<Collection, Event, Value, StreamEvent> =
+///     serde_json::from_str(frames::ITEM_LISTED)?;
+/// assert_eq!(broadcast.topic, Collection::Collection("wandernauts".to_owned()));
+/// assert_eq!(broadcast.event, message::Event::Event(Event::ItemListed));
+/// assert!(matches!(
+///     broadcast.into_custom_payload(),
+///     Some(StreamEvent { payload: Payload::ItemListed(_), .. })
+/// ));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod frames {
+    /// Reply to joining `collection:wandernauts`.
+    pub const JOIN_REPLY: &str =
+        r#"[1,1,"collection:wandernauts","phx_reply",{"response":{},"status":"ok"}]"#;
+
+    /// Broadcast of an [`ItemListed`](crate::schema::Payload::ItemListed) event to `collection:wandernauts`.
+    pub const ITEM_LISTED: &str = r#"[null,null,"collection:wandernauts","item_listed",{
+        "event_type": "item_listed",
+        "sent_at": "2022-09-12T08:26:47.105386+00:00",
+        "payload": {
+            "base_price": "120000000000000000",
+            "collection": { "slug": "wandernauts" },
+            "event_timestamp": "2022-09-12T08:26:46.976364+00:00",
+            "expiration_date": "2022-10-12T08:26:26.000000+00:00",
+            "is_private": false,
+            "item": {
+                "chain": { "name": "ethereum" },
+                "metadata": {
+                    "animation_url": null,
+                    "image_url": "https://i.seadn.io/gae/wandernaut-1234.png",
+                    "metadata_url": "https://example.com/metadata/1234",
+                    "name": "Wandernaut #1234",
+                    "description": null
+                },
+                "nft_id": "ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234",
+                "permalink": "https://opensea.io/assets/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234"
+            },
+            "listing_date": "2022-09-12T08:26:26.000000+00:00",
+            "listing_type": null,
+            "maker": { "address": "0xdaeeb975729fae923d5a4fd12aabfe228f219e9c" },
+            "payment_token": {
+                "address": "0x0000000000000000000000000000000000000000",
+                "decimals": 18,
+                "eth_price": "1.000000000000000",
+                "name": "Ether",
+                "symbol": "ETH",
+                "usd_price": "1717.359999999999900000"
+            },
+            "quantity": 1,
+            "taker": null
+        }
+    }]"#;
+
+    /// The server closing the `collection:wandernauts` channel.
+    pub const CLOSE: &str = r#"[1,3,"collection:wandernauts","phx_close",{}]"#;
+}
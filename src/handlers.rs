@@ -0,0 +1,116 @@
+use crate::{schema::StreamEvent, Collection, Event};
+use phyllo::message::Message;
+use serde_json::Value;
+use std::{
+    collections::hash_map::DefaultHasher,
+    future::Future,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+use tokio::{
+    sync::{broadcast, mpsc},
+    task::{JoinHandle, JoinSet},
+};
+
+/// Number of events that can be queued for each worker before the dispatcher waits.
+const WORKER_BUFFER: usize = 16;
+
+/// Spawns tasks that call `handler` on every event received by `subscription`, with
+/// up to `concurrency` events being handled at once.
+///
+/// Events for the same [`NftId`](crate::schema::NftId) are always handled in the order
+/// they were received, one at a time. Messages without a payload are skipped, as are
+/// events which `subscription` missed by lagging behind.
+///
+/// The returned task finishes once `subscription` is closed and every handler has returned.
+///
+/// # Panics
+/// Panics if `concurrency` is `0`.
+///
+/// If `handler` panics, no further events are dispatched. Events already queued for other
+/// handlers are still handled, after which the panic is resumed in the returned task, so
+/// awaiting it returns a [`JoinError`](tokio::task::JoinError) for which `is_panic()` is true.
+/// ```no_run
+/// # use opensea_stream::{client, handlers::spawn_handlers, subscribe_to, Collection, Network};
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+/// let (_handler, subscription) = subscribe_to(&client, Collection::All).await?;
+///
+/// spawn_handlers(subscription, 8, |event| async move {
+///     println!("{:?}", event);
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn spawn_handlers<F, Fut>(
+    mut subscription: broadcast::Receiver<Message<Collection, Event, Value, StreamEvent>>,
+    concurrency: usize,
+    handler: F,
+) -> JoinHandle<()>
+where
+    F: Fn(StreamEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    assert!(concurrency > 0, "concurrency must be at least 1");
+
+    let handler = Arc::new(handler);
+    let mut workers = JoinSet::new();
+    let senders: Vec<_> = (0..concurrency)
+        .map(|_| {
+            let (tx, mut rx) = mpsc::channel(WORKER_BUFFER);
+            let handler = handler.clone();
+            workers.spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    handler(event).await;
+                }
+            });
+            tx
+        })
+        .collect();
+
+    tokio::spawn(async move {
+        let mut failed = None;
+        loop {
+            let message = tokio::select! {
+                message = subscription.recv() => message,
+                // Workers only finish while events are being dispatched if they panicked.
+                Some(result) = workers.join_next() => {
+                    failed = result.err();
+                    break;
+                }
+            };
+
+            let event = match message {
+                Ok(message) => match message.into_custom_payload() {
+                    Some(event) => event,
+                    None => continue,
+                },
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let mut hasher = DefaultHasher::new();
+            event.payload.context().item.nft_id.hash(&mut hasher);
+            let worker = (hasher.finish() % concurrency as u64) as usize;
+
+            if senders[worker].send(event).await.is_err() {
+                // The worker has panicked; its panic is collected below.
+                break;
+            }
+        }
+
+        drop(senders);
+        while let Some(result) = workers.join_next().await {
+            if let Err(e) = result {
+                failed.get_or_insert(e);
+            }
+        }
+        if let Some(e) = failed {
+            if e.is_panic() {
+                std::panic::resume_unwind(e.into_panic());
+            }
+        }
+    })
+}
@@ -13,12 +13,12 @@
 //! # use phyllo::message::Payload;
 //! #[tokio::main]
 //! async fn main() -> anyhow::Result<()> {
-//!     let mut client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+//!     let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
 //!
 //!     // Subscribe to a collection. Note that you must all subscribe to all events
 //!     // in the collection; filtering is your responsibility (see below).
 //!     let (handler, mut subscription) = subscribe_to(
-//!         &mut client,
+//!         &client,
 //!         Collection::Collection("wandernauts".to_string()),
 //!     )
 //!     .await?;
@@ -51,6 +51,11 @@
 //! ```toml
 //! opensea-stream = { version = "0.1", default-features = false, features = ["rustls-tls-webpki-roots"] }
 //! ```
+//!
+//! `rust_decimal` adds helpers for converting prices to [`rust_decimal::Decimal`](https://docs.rs/rust_decimal)s.
+//!
+//! `fixtures` exposes sample messages for every event type in the [`fixtures`] module, which can be
+//! used to test code that consumes [`StreamEvent`]s.
 
 use phyllo::{
     channel::{ChannelBuilder, ChannelHandler},
@@ -59,19 +64,37 @@ use phyllo::{
     socket::{SocketBuilder, SocketHandler},
 };
 use schema::StreamEvent;
+use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
+use std::fmt::Debug;
 use tokio::sync::broadcast;
 use url::Url;
 
 pub use phyllo;
 
+/// Sources of the current time, for time-dependent helpers such as [`filter::Filter`].
+pub mod clock;
+/// Subscriptions that deliver events with metadata about their receipt.
+pub mod envelope;
+/// Filters for events received from the websocket.
+pub mod filter;
+#[cfg(feature = "fixtures")]
+/// Sample messages for every event type, for use in tests.
+pub mod fixtures;
+/// Concurrent handling of events received from the websocket.
+pub mod handlers;
 mod protocol;
 /// Payload schema for messages received from the websocket.
 pub mod schema;
+/// Serde helpers for the encodings used in [`schema`].
+pub mod serde_helpers;
 
 pub use protocol::*;
 
 /// Creates a client.
+///
+/// The returned [`SocketHandler`] is cheap to clone and can be shared between tasks
+/// to subscribe to collections concurrently.
 pub async fn client(network: Network, token: &str) -> SocketHandler<Collection> {
     let mut network: Url = Url::from(network);
     network.query_pairs_mut().append_pair("token", token);
@@ -79,8 +102,13 @@ pub async fn client(network: Network, token: &str) -> SocketHandler<Collection>
 }
 
 /// Subscribes to all the events of a particular [`Collection`].
+///
+/// Messages whose payload fails to decode into a [`StreamEvent`] are dropped by phyllo without
+/// an error. This includes events of a type not known to this version of the crate
+/// ([`Event::Unknown`]); use [`subscribe_to_with`] with a
+/// [`StreamEventHeader`](schema::StreamEventHeader) or a [`Value`] to receive them.
 pub async fn subscribe_to(
-    socket: &mut SocketHandler<Collection>,
+    socket: &SocketHandler<Collection>,
     collection: Collection,
 ) -> Result<
     (
@@ -89,20 +117,72 @@ pub async fn subscribe_to(
     ),
     RegisterChannelError,
 > {
-    socket.channel(ChannelBuilder::new(collection)).await
+    subscribe_to_with(socket, collection).await
+}
+
+/// Subscribes to all the events of a particular [`Collection`], decoding each
+/// payload into `R` instead of [`StreamEvent`].
+///
+/// This is useful for receiving fields not yet covered by [`schema`], or the raw
+/// [`Value`] of each event.
+/// ```no_run
+/// # use opensea_stream::{client, subscribe_to_with, Collection, Network};
+/// # use serde_json::Value;
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+/// let (_handler, mut subscription) =
+///     subscribe_to_with::<Value>(&client, Collection::All).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn subscribe_to_with<R>(
+    socket: &SocketHandler<Collection>,
+    collection: Collection,
+) -> Result<
+    (
+        ChannelHandler<Collection, Event, Value, R>,
+        broadcast::Receiver<Message<Collection, Event, Value, R>>,
+    ),
+    RegisterChannelError,
+>
+where
+    R: Serialize + DeserializeOwned + Clone + Send + 'static + Debug,
+{
+    socket
+        .clone()
+        .channel(ChannelBuilder::new(collection))
+        .await
 }
 
 /// Subscribes to all the events of a particular [`Collection`] using
 /// a custom configuration.
-pub async fn subscribe_to_with_config(
-    socket: &mut SocketHandler<Collection>,
+///
+/// Like [`subscribe_to_with`], each payload is decoded into `R`, which is usually
+/// [`StreamEvent`].
+/// ```no_run
+/// # use opensea_stream::{client, schema::StreamEvent, subscribe_to_with_config, Collection, Network};
+/// # use phyllo::channel::ChannelBuilder;
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+/// let (_handler, mut subscription) =
+///     subscribe_to_with_config::<StreamEvent>(&client, ChannelBuilder::new(Collection::All)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn subscribe_to_with_config<R>(
+    socket: &SocketHandler<Collection>,
     channel_builder: ChannelBuilder<Collection>,
 ) -> Result<
     (
-        ChannelHandler<Collection, Event, Value, StreamEvent>,
-        broadcast::Receiver<Message<Collection, Event, Value, StreamEvent>>,
+        ChannelHandler<Collection, Event, Value, R>,
+        broadcast::Receiver<Message<Collection, Event, Value, R>>,
     ),
     RegisterChannelError,
-> {
-    socket.channel(channel_builder).await
+>
+where
+    R: Serialize + DeserializeOwned + Clone + Send + 'static + Debug,
+{
+    socket.clone().channel(channel_builder).await
 }
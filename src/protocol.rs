@@ -1,4 +1,7 @@
-use serde::{de::Error, Deserialize, Serialize};
+use serde::{
+    de::{Error, Visitor},
+    Deserialize, Serialize,
+};
 use std::fmt::Display;
 use url::Url;
 
@@ -11,16 +14,104 @@ pub enum Collection {
     All,
 }
 
+impl Collection {
+    /// Slug of the collection, or `None` for [`Collection::All`].
+    pub fn slug(&self) -> Option<&str> {
+        match self {
+            Collection::Collection(slug) => Some(slug),
+            Collection::All => None,
+        }
+    }
+
+    /// Slug of the collection, or `*` for [`Collection::All`].
+    pub fn as_str(&self) -> &str {
+        match self {
+            Collection::Collection(slug) => slug,
+            Collection::All => "*",
+        }
+    }
+
+    /// Consumes the collection, returning its slug, or `*` for [`Collection::All`].
+    pub fn into_inner(self) -> String {
+        match self {
+            Collection::Collection(slug) => slug,
+            Collection::All => "*".to_owned(),
+        }
+    }
+
+    /// Returns whether `slug` looks like a valid collection slug,
+    /// i.e. it is non-empty and only contains lowercase ASCII letters, digits, `-` and `_`.
+    ///
+    /// This is used by [`collection!`](crate::collection!) to check slugs at compile time.
+    pub const fn is_valid_slug(slug: &str) -> bool {
+        let bytes = slug.as_bytes();
+        if bytes.is_empty() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' => {}
+                _ => return false,
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+/// Creates a [`Collection`] from a slug literal, checking the slug at compile time.
+///
+/// ```
+/// use opensea_stream::{collection, Collection};
+///
+/// let wandernauts = collection!("wandernauts");
+/// assert_eq!(wandernauts, Collection::Collection("wandernauts".to_owned()));
+/// ```
+///
+/// Typos such as uppercase letters or spaces fail to compile:
+/// ```compile_fail
+/// let wandernauts = opensea_stream::collection!("Wander Nauts");
+/// ```
+#[macro_export]
+macro_rules! collection {
+    ($slug:literal) => {{
+        const _: () = assert!(
+            $crate::Collection::is_valid_slug($slug),
+            "invalid collection slug"
+        );
+        $crate::Collection::Collection(::std::string::String::from($slug))
+    }};
+}
+
+impl From<crate::schema::Collection> for Collection {
+    fn from(collection: crate::schema::Collection) -> Self {
+        Collection::Collection(collection.into_inner())
+    }
+}
+
+impl PartialEq<str> for Collection {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Collection {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<crate::schema::Collection> for Collection {
+    fn eq(&self, other: &crate::schema::Collection) -> bool {
+        other == self
+    }
+}
+
 impl Display for Collection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "collection:{}",
-            match &self {
-                Collection::Collection(c) => c,
-                Collection::All => "*",
-            }
-        )
+        write!(f, "collection:{}", self.as_str())
     }
 }
 
@@ -55,7 +146,7 @@ impl<'de> Deserialize<'de> for Collection {
 /// OpenSea provides two websockets for either `Mainnet` (production) networks for `Testnet` networks.
 /// See [`Chain`](crate::schema::Chain) for a full list of supported chains.
 pub enum Network {
-    /// Mainnet (`Ethereum`, `Polygon`, `Klaytn`, `Solana`)
+    /// Mainnet (`Ethereum`, `Polygon`, `Klaytn`, `Solana`, `Base`)
     Mainnet,
     /// Testnet (`Goerli`, `Mumbai`, `Baobab`)
     Testnet,
@@ -78,8 +169,14 @@ impl From<Network> for Url {
 ///
 /// This type belongs to the `event` field of [`Message`](phyllo::message::Message), not to be confused with
 /// [`Payload`](crate::schema::Payload).
-#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
-#[serde(rename_all = "snake_case")]
+///
+/// Events that are not known to this crate are kept in [`Event::Unknown`] instead of failing to deserialize.
+/// Their payloads cannot be decoded into a [`StreamEvent`](crate::schema::StreamEvent), so
+/// [`subscribe_to`](crate::subscribe_to) drops them; they are only received when subscribing with
+/// [`subscribe_to_with`](crate::subscribe_to_with) and a payload type such as
+/// [`StreamEventHeader`](crate::schema::StreamEventHeader) or [`Value`](serde_json::Value).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Event {
     /// An item been listed for sale.
     ItemListed,
@@ -95,4 +192,166 @@ pub enum Event {
     ItemReceivedOffer,
     /// An item has received a bid.
     ItemReceivedBid,
+    /// An event not known to this version of the crate.
+    ///
+    /// [`subscribe_to`](crate::subscribe_to) never receives these, as their payloads cannot be
+    /// decoded into a [`StreamEvent`](crate::schema::StreamEvent).
+    Unknown(String),
+}
+
+impl Event {
+    const KNOWN: [Event; 7] = [
+        Event::ItemListed,
+        Event::ItemSold,
+        Event::ItemTransferred,
+        Event::ItemMetadataUpdated,
+        Event::ItemCancelled,
+        Event::ItemReceivedOffer,
+        Event::ItemReceivedBid,
+    ];
+
+    /// Name of the event as sent by the websocket, e.g. `item_listed`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Event::ItemListed => "item_listed",
+            Event::ItemSold => "item_sold",
+            Event::ItemTransferred => "item_transferred",
+            Event::ItemMetadataUpdated => "item_metadata_updated",
+            Event::ItemCancelled => "item_cancelled",
+            Event::ItemReceivedOffer => "item_received_offer",
+            Event::ItemReceivedBid => "item_received_bid",
+            Event::Unknown(s) => s,
+        }
+    }
+
+    /// Position of the event in an [`EventSet`]. Unknown events have no position.
+    fn bit(&self) -> Option<u16> {
+        Event::KNOWN.iter().position(|e| e == self).map(|i| 1 << i)
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = Event;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an event name")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Event::KNOWN
+                    .into_iter()
+                    .find(|e| e.as_str() == value)
+                    .unwrap_or_else(|| Event::Unknown(value.to_owned())))
+            }
+        }
+
+        deserializer.deserialize_str(Helper)
+    }
+}
+
+/// A set of known [`Event`]s.
+/// ```
+/// # use opensea_stream::{Event, EventSet};
+/// let set: EventSet = [Event::ItemListed, Event::ItemSold].into_iter().collect();
+///
+/// assert!(set.contains(&Event::ItemSold));
+/// assert!(!set.contains(&Event::ItemTransferred));
+/// assert!(!EventSet::all().contains(&Event::Unknown("item_minted".to_string())));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventSet(u16);
+
+impl EventSet {
+    /// Constructs an empty `EventSet`.
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Constructs an `EventSet` containing every known event.
+    pub fn all() -> Self {
+        Event::KNOWN.into_iter().collect()
+    }
+
+    /// Adds an event to the set, returning whether it was added.
+    /// [`Event::Unknown`] cannot be added.
+    pub fn insert(&mut self, event: &Event) -> bool {
+        match event.bit() {
+            Some(bit) if self.0 & bit == 0 => {
+                self.0 |= bit;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes an event from the set, returning whether it was present.
+    pub fn remove(&mut self, event: &Event) -> bool {
+        let present = self.contains(event);
+        if let Some(bit) = event.bit() {
+            self.0 &= !bit;
+        }
+        present
+    }
+
+    /// Returns whether the set contains an event. [`Event::Unknown`] is never contained.
+    pub fn contains(&self, event: &Event) -> bool {
+        event.bit().is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the events in the set.
+    pub fn iter(&self) -> impl Iterator<Item = Event> + '_ {
+        Event::KNOWN.into_iter().filter(|e| self.contains(e))
+    }
+}
+
+impl From<Event> for EventSet {
+    fn from(event: Event) -> Self {
+        std::iter::once(event).collect()
+    }
+}
+
+impl FromIterator<Event> for EventSet {
+    fn from_iter<I: IntoIterator<Item = Event>>(iter: I) -> Self {
+        let mut set = EventSet::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Event> for EventSet {
+    fn extend<I: IntoIterator<Item = Event>>(&mut self, iter: I) {
+        for event in iter {
+            self.insert(&event);
+        }
+    }
 }
@@ -1,23 +1,235 @@
-use crate::Event;
+use crate::{
+    serde_helpers::{
+        address_fromjson, address_fromjson_opt, datetime, expiration_date, f64_fromstring,
+        number_lenient, u256_fromstr_radix_10, url_lenient, vec_lenient,
+    },
+    Event,
+};
 use chrono::{DateTime, Utc};
 use ethers_core::{
     abi::Address,
-    types::{H256, U256},
+    types::{H160, H256, U256},
+};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+use serde::{
+    de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor},
+    Deserialize, Serialize,
 };
-use serde::{de::Error, Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 use url::Url;
 
 /// Payload of a message received from the websocket.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Serializing a `StreamEvent` produces JSON of the same shape as OpenSea sends, which
+/// deserializes back into the same event. It is not byte-for-byte identical to the original:
+/// timestamps are written in RFC 3339 with `Z`, `eth_price` and `usd_price` are written
+/// with the precision of an `f64`, and fields not covered by the schema are dropped.
+///
+/// In particular, sentinel expiration dates such as `1970-01-01T00:00:00Z` come back as `null`
+/// (see [`expiration_date`]), metadata URLs that are not absolute URLs come back as `null`,
+/// and trait keys other than those of [`Trait`] such as `order` are dropped, as are traits
+/// that cannot be decoded.
+#[derive(Serialize, Debug, Clone)]
 pub struct StreamEvent {
     /// Timestamp of when this message was sent to the client.
+    #[serde(with = "datetime")]
     pub sent_at: DateTime<Utc>,
     /// Contents of the message
     #[serde(flatten)]
     pub payload: Payload,
 }
 
+// `#[serde(flatten)]` buffers the entire payload before decoding it, so `StreamEvent`
+// is deserialized by hand. The payload is only buffered if it arrives before `event_type`.
+impl<'de> Deserialize<'de> for StreamEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            SentAt,
+            EventType,
+            Payload,
+            #[serde(other)]
+            Other,
+        }
+
+        #[derive(Deserialize)]
+        struct SentAt(#[serde(with = "datetime")] DateTime<Utc>);
+
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = StreamEvent;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a stream event")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut sent_at = None;
+                let mut event: Option<Event> = None;
+                let mut payload = None;
+                let mut buffered = None;
+
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::SentAt => sent_at = Some(map.next_value::<SentAt>()?.0),
+                        Field::EventType => event = Some(map.next_value()?),
+                        Field::Payload => match &event {
+                            Some(event) => {
+                                payload = Some(map.next_value_seed(PayloadSeed(event.clone()))?)
+                            }
+                            None => buffered = Some(map.next_value::<serde_json::Value>()?),
+                        },
+                        Field::Other => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let event = event.ok_or_else(|| A::Error::missing_field("event_type"))?;
+                let payload = match (payload, buffered) {
+                    (Some(payload), _) => payload,
+                    (None, Some(buffered)) => PayloadSeed(event)
+                        .deserialize(buffered)
+                        .map_err(A::Error::custom)?,
+                    (None, None) => return Err(A::Error::missing_field("payload")),
+                };
+
+                Ok(StreamEvent {
+                    sent_at: sent_at.ok_or_else(|| A::Error::missing_field("sent_at"))?,
+                    payload,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(Helper)
+    }
+}
+
+/// Deserializes the payload of an event whose type is already known.
+struct PayloadSeed(Event);
+
+impl<'de> DeserializeSeed<'de> for PayloadSeed {
+    type Value = Payload;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match self.0 {
+            Event::ItemListed => Payload::ItemListed(Deserialize::deserialize(deserializer)?),
+            Event::ItemSold => Payload::ItemSold(Deserialize::deserialize(deserializer)?),
+            Event::ItemTransferred => {
+                Payload::ItemTransferred(Deserialize::deserialize(deserializer)?)
+            }
+            Event::ItemMetadataUpdated => {
+                Payload::ItemMetadataUpdated(Deserialize::deserialize(deserializer)?)
+            }
+            Event::ItemCancelled => Payload::ItemCancelled(Deserialize::deserialize(deserializer)?),
+            Event::ItemReceivedOffer => {
+                Payload::ItemReceivedOffer(Deserialize::deserialize(deserializer)?)
+            }
+            Event::ItemReceivedBid => {
+                Payload::ItemReceivedBid(Deserialize::deserialize(deserializer)?)
+            }
+            Event::Unknown(event) => {
+                return Err(D::Error::custom(format!("unknown event type {}", event)))
+            }
+        })
+    }
+}
+
+/// Header of a [`StreamEvent`], with the rest of the payload left undecoded.
+///
+/// Decoding a header skips decoding the payload into typed fields, but the payload is still
+/// built as a [`Value`](serde_json::Value). From a `Value`, as phyllo decodes messages, a header
+/// takes around 60% of the time of a full [`StreamEvent`] (see `benches/parse.rs`).
+/// Consumers that discard most events can subscribe with
+/// [`subscribe_to_with`](crate::subscribe_to_with) and only decode the events they keep,
+/// e.g. those passing [`Filter::matches_header`](crate::filter::Filter::matches_header).
+/// ```no_run
+/// # use opensea_stream::{client, schema::StreamEventHeader, subscribe_to_with, Collection, Event, Network};
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let client = client(Network::Mainnet, "YOUR_API_KEY_HERE").await;
+/// let (_handler, mut subscription) =
+///     subscribe_to_with::<StreamEventHeader>(&client, Collection::All).await?;
+///
+/// loop {
+///     let header = match subscription.recv().await?.into_custom_payload() {
+///         Some(v) => v,
+///         None => continue,
+///     };
+///
+///     if header.event_type == Event::ItemSold && header.collection.slug() == "wandernauts" {
+///         let event = header.into_stream_event()?;
+///         println!("{:?}", event);
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct StreamEventHeader {
+    /// Timestamp of when this message was sent to the client.
+    #[serde(with = "datetime")]
+    pub sent_at: DateTime<Utc>,
+    /// Type of the event.
+    pub event_type: Event,
+    /// Collection that the item belongs to.
+    #[serde(skip)]
+    pub collection: Collection,
+    /// Undecoded contents of the message.
+    pub payload: serde_json::Value,
+}
+
+impl StreamEventHeader {
+    /// Decodes the rest of the payload.
+    pub fn into_stream_event(self) -> Result<StreamEvent, serde_json::Error> {
+        Ok(StreamEvent {
+            sent_at: self.sent_at,
+            payload: PayloadSeed(self.event_type).deserialize(self.payload)?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for StreamEventHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Inner {
+            #[serde(with = "datetime")]
+            sent_at: DateTime<Utc>,
+            event_type: Event,
+            payload: serde_json::Value,
+        }
+
+        let inner = Inner::deserialize(deserializer)?;
+        let collection = inner
+            .payload
+            .get("collection")
+            .map(Collection::deserialize)
+            .ok_or_else(|| D::Error::missing_field("collection"))?
+            .map_err(D::Error::custom)?;
+
+        Ok(StreamEventHeader {
+            sent_at: inner.sent_at,
+            event_type: inner.event_type,
+            collection,
+            payload: inner.payload,
+        })
+    }
+}
+
 /// Content of the message.
 ///
 /// This type corresponds to the JSON objects recieved [as described here](https://docs.opensea.io/reference/stream-api-event-schemas),
@@ -25,6 +237,7 @@ pub struct StreamEvent {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "event_type", content = "payload")]
 #[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
 pub enum Payload {
     /// An item has been listed for sale.
     ItemListed(ItemListedData),
@@ -42,8 +255,23 @@ pub enum Payload {
     ItemReceivedBid(ItemReceivedBidData),
 }
 
-impl From<Payload> for Event {
-    fn from(val: Payload) -> Self {
+impl Payload {
+    /// Context of the event.
+    pub fn context(&self) -> &Context {
+        match self {
+            Payload::ItemListed(v) => &v.context,
+            Payload::ItemSold(v) => &v.context,
+            Payload::ItemTransferred(v) => &v.context,
+            Payload::ItemMetadataUpdated(v) => &v.context,
+            Payload::ItemCancelled(v) => &v.context,
+            Payload::ItemReceivedOffer(v) => &v.context,
+            Payload::ItemReceivedBid(v) => &v.context,
+        }
+    }
+}
+
+impl From<&Payload> for Event {
+    fn from(val: &Payload) -> Self {
         match val {
             Payload::ItemListed(_) => Event::ItemListed,
             Payload::ItemSold(_) => Event::ItemSold,
@@ -56,6 +284,12 @@ impl From<Payload> for Event {
     }
 }
 
+impl From<Payload> for Event {
+    fn from(val: Payload) -> Self {
+        Event::from(&val)
+    }
+}
+
 /// Context for a message (token and collection)
 ///
 /// This struct is present in every [`Payload`].
@@ -67,10 +301,153 @@ pub struct Context {
     pub item: Item,
 }
 
+// `#[serde(flatten)]` buffers the context before decoding it, so payload data is deserialized
+// through a private struct that has the context's fields inline.
+macro_rules! payload_data {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                pub $field:ident: $ty:ty,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name {
+            /// Context
+            #[serde(flatten)]
+            pub context: Context,
+
+            $(
+                $(#[$field_meta])*
+                pub $field: $ty,
+            )*
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Data {
+                    collection: Collection,
+                    item: Item,
+                    $(
+                        $(#[$field_meta])*
+                        $field: $ty,
+                    )*
+                }
+
+                let data = Data::deserialize(deserializer)?;
+                Ok($name {
+                    context: Context {
+                        collection: data.collection,
+                        item: data.item,
+                    },
+                    $($field: data.$field,)*
+                })
+            }
+        }
+    };
+}
+
 /// A collection on OpenSea.
-#[derive(Debug, Clone)]
+///
+/// This can be compared with the [`Collection`](crate::Collection) that was subscribed to, or with a slug.
+///
+/// Comparing with [`Collection::All`](crate::Collection::All) is always `false`, even though
+/// a `collection:*` subscription receives events from every collection. Check for `All`
+/// separately before comparing when the subscription may be to every collection.
+/// ```
+/// # use opensea_stream::{schema, Collection};
+/// let collection = schema::Collection::from("wandernauts");
+/// assert!(collection == "wandernauts");
+/// assert!(collection == opensea_stream::collection!("wandernauts"));
+/// assert!(collection != Collection::All);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Collection(String);
 
+impl Collection {
+    /// Slug of the collection, as seen in its URL.
+    pub fn slug(&self) -> &str {
+        &self.0
+    }
+
+    /// Alias for [`Collection::slug`].
+    pub fn as_str(&self) -> &str {
+        self.slug()
+    }
+
+    /// Consumes the collection, returning its slug.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for Collection {
+    fn from(slug: String) -> Self {
+        Collection(slug)
+    }
+}
+
+impl From<&str> for Collection {
+    fn from(slug: &str) -> Self {
+        Collection(slug.to_owned())
+    }
+}
+
+impl TryFrom<crate::Collection> for Collection {
+    type Error = AllCollections;
+
+    /// Converts a subscribed collection, failing for [`Collection::All`](crate::Collection::All).
+    /// ```
+    /// # use opensea_stream::{schema, Collection};
+    /// let collection = schema::Collection::try_from(opensea_stream::collection!("wandernauts"));
+    /// assert_eq!(collection, Ok(schema::Collection::from("wandernauts")));
+    /// assert!(schema::Collection::try_from(Collection::All).is_err());
+    /// ```
+    fn try_from(collection: crate::Collection) -> Result<Self, Self::Error> {
+        match collection {
+            crate::Collection::Collection(slug) => Ok(Collection(slug)),
+            crate::Collection::All => Err(AllCollections),
+        }
+    }
+}
+
+/// [`Collection::All`](crate::Collection::All) does not name a single collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllCollections;
+
+impl fmt::Display for AllCollections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "collection:* does not name a single collection")
+    }
+}
+
+impl std::error::Error for AllCollections {}
+
+impl PartialEq<str> for Collection {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Collection {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Always `false` for [`Collection::All`](crate::Collection::All).
+impl PartialEq<crate::Collection> for Collection {
+    fn eq(&self, other: &crate::Collection) -> bool {
+        other.slug() == Some(self.slug())
+    }
+}
+
 impl Serialize for Collection {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -115,8 +492,51 @@ pub struct Item {
     pub metadata: Metadata,
 }
 
+impl Item {
+    /// Chain the item is on, taken from `chain`.
+    ///
+    /// This is the same as `nft_id.network` in well-formed payloads.
+    /// Use [`Item::checked_chain`] to detect payloads where they disagree.
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Chain the item is on, or an error if `chain` and `nft_id.network` disagree.
+    pub fn checked_chain(&self) -> Result<Chain, ChainMismatch> {
+        if self.chain == self.nft_id.network {
+            Ok(self.chain)
+        } else {
+            Err(ChainMismatch {
+                chain: self.chain,
+                nft_id: self.nft_id.network,
+            })
+        }
+    }
+}
+
+/// The chain of an [`Item`] does not match the chain of its [`NftId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainMismatch {
+    /// Chain from `item.chain`.
+    pub chain: Chain,
+    /// Chain from `item.nft_id`.
+    pub nft_id: Chain,
+}
+
+impl fmt::Display for ChainMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "item is on {} but its nft_id is on {}",
+            self.chain, self.nft_id
+        )
+    }
+}
+
+impl std::error::Error for ChainMismatch {}
+
 /// Identifier of the NFT.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NftId {
     /// Chain the item is on.
     pub network: Chain,
@@ -126,6 +546,89 @@ pub struct NftId {
     pub id: U256,
 }
 
+impl NftId {
+    /// Link to the item's page on OpenSea.
+    ///
+    /// Items on testnets link to `testnets.opensea.io`.
+    /// ```
+    /// # use opensea_stream::schema::{Chain, NftId};
+    /// let nft_id = NftId {
+    ///     network: Chain::Polygon,
+    ///     address: "0xff9243a8f506b40928b5b7a767c76fb008f86beb".parse()?,
+    ///     id: 77.into(),
+    /// };
+    /// let permalink = nft_id.permalink();
+    /// assert_eq!(
+    ///     permalink.as_str(),
+    ///     "https://opensea.io/assets/matic/0xff9243a8f506b40928b5b7a767c76fb008f86beb/77"
+    /// );
+    /// assert_eq!(NftId::from_permalink(&permalink), Some(nft_id));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn permalink(&self) -> Url {
+        let host = match self.network {
+            Chain::Goerli | Chain::Mumbai | Chain::Baobab => "testnets.opensea.io",
+            #[allow(deprecated)]
+            Chain::Rinkeby => "testnets.opensea.io",
+            _ => "opensea.io",
+        };
+        Url::parse(&format!(
+            "https://{}/assets/{}/{:?}/{}",
+            host, self.network, self.address, self.id
+        ))
+        .expect("permalink should be a valid URL")
+    }
+
+    /// Parses the identifier of an item from a link to its page on OpenSea.
+    ///
+    /// `/item/{chain}/{address}/{id}`, `/assets/{chain}/{address}/{id}` and the older
+    /// Ethereum-only `/assets/{address}/{id}` forms are accepted.
+    /// `None` is returned if the URL is not an item page on OpenSea.
+    /// ```
+    /// # use opensea_stream::schema::{Chain, NftId};
+    /// # use url::Url;
+    /// let address = "0xa4c123b1612dd272d1371c17149d439536b3216f".parse()?;
+    /// for (permalink, network) in [
+    ///     ("https://opensea.io/item/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234", Chain::Ethereum),
+    ///     ("https://opensea.io/assets/ethereum/0xa4c123b1612dd272d1371c17149d439536b3216f/1234", Chain::Ethereum),
+    ///     ("https://opensea.io/assets/0xa4c123b1612dd272d1371c17149d439536b3216f/1234", Chain::Ethereum),
+    ///     ("https://testnets.opensea.io/assets/goerli/0xa4c123b1612dd272d1371c17149d439536b3216f/1234", Chain::Goerli),
+    /// ] {
+    ///     let nft_id = NftId::from_permalink(&Url::parse(permalink)?);
+    ///     assert_eq!(nft_id, Some(NftId { network, address, id: 1234.into() }));
+    /// }
+    ///
+    /// // Testnet items always name their chain.
+    /// let chainless = Url::parse(
+    ///     "https://testnets.opensea.io/assets/0xa4c123b1612dd272d1371c17149d439536b3216f/1234",
+    /// )?;
+    /// assert_eq!(NftId::from_permalink(&chainless), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_permalink(url: &Url) -> Option<NftId> {
+        let testnet = match url.host_str()? {
+            "opensea.io" | "www.opensea.io" => false,
+            "testnets.opensea.io" => true,
+            _ => return None,
+        };
+
+        let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+        let (network, address, id) = match segments[..] {
+            ["assets" | "item", network, address, id] => {
+                (Chain::from_str(network).ok()?, address, id)
+            }
+            ["assets", address, id] if !testnet => (Chain::Ethereum, address, id),
+            _ => return None,
+        };
+
+        Some(NftId {
+            network,
+            address: Address::from_str(address).ok()?,
+            id: U256::from_dec_str(id).ok()?,
+        })
+    }
+}
+
 impl Serialize for NftId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -140,32 +643,48 @@ impl<'de> Deserialize<'de> for NftId {
     where
         D: serde::Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
-        let mut parts = s.splitn(3, '/').fuse();
-
-        let network = parts
-            .next()
-            .map(Chain::from_str)
-            .ok_or_else(|| D::Error::custom("expected network"))?
-            .map_err(|_| D::Error::custom("invalid network"))?;
-
-        let address = parts
-            .next()
-            .map(Address::from_str)
-            .ok_or_else(|| D::Error::custom("expected address"))?
-            .map_err(D::Error::custom)?;
+        struct Helper;
 
-        let id = parts
-            .next()
-            .map(U256::from_dec_str)
-            .ok_or_else(|| D::Error::custom("expected id"))?
-            .map_err(D::Error::custom)?;
+        impl<'de> Visitor<'de> for Helper {
+            type Value = NftId;
 
-        Ok(NftId {
-            network,
-            address,
-            id,
-        })
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string of the form network/address/id")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                let mut parts = value.splitn(3, '/').fuse();
+
+                let network = parts
+                    .next()
+                    .map(Chain::from_str)
+                    .ok_or_else(|| E::custom("expected network"))?
+                    .map_err(|_| E::custom("invalid network"))?;
+
+                let address = parts
+                    .next()
+                    .map(Address::from_str)
+                    .ok_or_else(|| E::custom("expected address"))?
+                    .map_err(E::custom)?;
+
+                let id = parts
+                    .next()
+                    .map(U256::from_dec_str)
+                    .ok_or_else(|| E::custom("expected id"))?
+                    .map_err(E::custom)?;
+
+                Ok(NftId {
+                    network,
+                    address,
+                    id,
+                })
+            }
+        }
+
+        deserializer.deserialize_str(Helper)
     }
 }
 
@@ -174,7 +693,7 @@ mod chain {
     use serde::{Deserialize, Serialize};
 
     /// Network an item is on.
-    #[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     #[serde(tag = "name", rename_all = "lowercase")]
     #[non_exhaustive]
     pub enum Chain {
@@ -187,6 +706,8 @@ mod chain {
         Klaytn,
         /// [Solana](https://solana.com/) mainnet. This variant (and all events for Solana assets) are not supported in this version.
         Solana,
+        /// [Base](https://base.org/) mainnet.
+        Base,
 
         /// [Goerli](https://ethereum.org/en/developers/docs/networks/#goerli) testnet (of Ethereum).
         Goerli,
@@ -210,6 +731,8 @@ impl FromStr for Chain {
             "matic" => Ok(Chain::Polygon),
             "klaytn" => Ok(Chain::Klaytn),
             "solana" => Ok(Chain::Solana),
+            "base" => Ok(Chain::Base),
+            "goerli" => Ok(Chain::Goerli),
             #[allow(deprecated)]
             "rinkeby" => Ok(Chain::Rinkeby),
             "mumbai" => Ok(Chain::Mumbai),
@@ -229,6 +752,7 @@ impl fmt::Display for Chain {
                 Chain::Polygon => "matic",
                 Chain::Klaytn => "klaytn",
                 Chain::Solana => "solana",
+                Chain::Base => "base",
                 #[allow(deprecated)]
                 Chain::Rinkeby => "rinkeby",
                 Chain::Mumbai => "mumbai",
@@ -239,10 +763,52 @@ impl fmt::Display for Chain {
     }
 }
 
+impl Chain {
+    /// Base URL and address path of the chain's block explorer.
+    fn explorer(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Chain::Ethereum => Some(("https://etherscan.io", "address")),
+            Chain::Polygon => Some(("https://polygonscan.com", "address")),
+            Chain::Klaytn => Some(("https://scope.klaytn.com", "account")),
+            Chain::Base => Some(("https://basescan.org", "address")),
+            Chain::Goerli => Some(("https://goerli.etherscan.io", "address")),
+            Chain::Mumbai => Some(("https://mumbai.polygonscan.com", "address")),
+            Chain::Baobab => Some(("https://baobab.scope.klaytn.com", "account")),
+            #[allow(deprecated)]
+            Chain::Solana | Chain::Rinkeby => None,
+        }
+    }
+
+    /// Link to a transaction on the chain's block explorer, e.g. Etherscan for Ethereum.
+    ///
+    /// `None` is returned for chains without a supported explorer (Solana and Rinkeby).
+    /// ```
+    /// # use ethers_core::types::H256;
+    /// # use opensea_stream::schema::Chain;
+    /// let url = Chain::Base.transaction_url(H256::zero()).unwrap();
+    /// assert_eq!(
+    ///     url.as_str(),
+    ///     "https://basescan.org/tx/0x0000000000000000000000000000000000000000000000000000000000000000"
+    /// );
+    /// ```
+    pub fn transaction_url(&self, hash: H256) -> Option<Url> {
+        let (base, _) = self.explorer()?;
+        Url::parse(&format!("{}/tx/{:?}", base, hash)).ok()
+    }
+
+    /// Link to an address on the chain's block explorer, e.g. Etherscan for Ethereum.
+    ///
+    /// `None` is returned for chains without a supported explorer (Solana and Rinkeby).
+    pub fn address_url(&self, address: Address) -> Option<Url> {
+        let (base, path) = self.explorer()?;
+        Url::parse(&format!("{}/{}/{:?}", base, path, address)).ok()
+    }
+}
+
 /// Basic metadata of an item.
 ///
 /// This is fetched directly from an item's metadata according to [metadata standards](https://docs.opensea.io/docs/metadata-standards).
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Metadata {
     /// Name.
     pub name: Option<String>,
@@ -256,190 +822,669 @@ pub struct Metadata {
     pub metadata_url: Option<Url>,
 }
 
-/// Payload data for [`Payload::ItemListed`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemListedData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+payload_data! {
+    /// Payload data for [`Payload::ItemListed`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemListedData {
+        /// Timestamp of when the listing was created.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Starting price of the listing. See `payment_token` for the actual value of each unit.
+        #[serde(with = "u256_fromstr_radix_10")]
+        pub base_price: U256,
+        /// Expiration date. `None` indicates the listing does not expire.
+        #[serde(with = "expiration_date", default)]
+        pub expiration_date: Option<DateTime<Utc>>,
+        /// Whether the listing is private.
+        pub is_private: bool,
+        /// Timestamp of when the listing was created.
+        #[serde(with = "datetime")]
+        pub listing_date: DateTime<Utc>,
+        /// Type of listing. `None` indicates the listing is a buyout.
+        pub listing_type: Option<ListingType>,
+        /// Creator of the listing.
+        #[serde(with = "address_fromjson")]
+        pub maker: Address,
+        /// Token accepted for payment.
+        pub payment_token: PaymentToken,
+        /// Number of items on sale. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+        /// Buyer of the listing.
+        #[serde(with = "address_fromjson_opt", default)]
+        pub taker: Option<Address>,
+    }
+}
 
-    /// Timestamp of when the listing was created.
-    pub event_timestamp: DateTime<Utc>,
-    /// Starting price of the listing. See `payment_token` for the actual value of each unit.
-    #[serde(with = "u256_fromstr_radix_10")]
-    pub base_price: U256,
-    /// Expiration date.
-    pub expiration_date: DateTime<Utc>,
-    /// Whether the listing is private.
-    pub is_private: bool,
-    /// Timestamp of when the listing was created.
-    pub listing_date: DateTime<Utc>,
-    /// Type of listing. `None` indicates the listing is a buyout.
-    pub listing_type: Option<ListingType>,
-    /// Creator of the listing.
-    #[serde(with = "address_fromjson")]
-    pub maker: Address,
-    /// Token accepted for payment.
-    pub payment_token: PaymentToken,
-    /// Number of items on sale. This is always `1` for ERC-721 tokens.
-    pub quantity: u64,
-    /// Buyer of the listing.
-    #[serde(with = "address_fromjson_opt", default)]
-    pub taker: Option<Address>,
+impl ItemListedData {
+    /// Price of a single item, i.e. `base_price` divided by `quantity`.
+    ///
+    /// The result is rounded down. `None` is returned if `quantity` is `0`.
+    pub fn unit_price(&self) -> Option<U256> {
+        unit_price(self.base_price, self.quantity)
+    }
+
+    /// Price of a single item in whole units of `payment_token`, e.g. `0.5` for 0.5 ETH.
+    ///
+    /// `None` is returned if `quantity` is `0` or the price cannot be represented as a [`Decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn unit_price_decimal(&self) -> Option<Decimal> {
+        unit_price_decimal(&self.payment_token, self.base_price, self.quantity)
+    }
+
+    /// Returns whether the listing has expired at `now`.
+    ///
+    /// Listings without an expiration date never expire.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        is_expired(self.expiration_date, now)
+    }
+
+    /// Returns whether the listing is plausibly fillable at `now`, i.e. it has started and has not expired.
+    ///
+    /// Cancellations and sales are separate events, so they are not taken into account.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        is_active(self.listing_date, self.expiration_date, now)
+    }
 }
 
-/// Payload data for [`Payload::ItemSold`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemSoldData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+payload_data! {
+    /// Payload data for [`Payload::ItemSold`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemSoldData {
+        /// Timestamp of when the item was sold.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Timestamp of when the listing was closed.
+        #[serde(with = "datetime")]
+        pub closing_date: DateTime<Utc>,
+        /// Whether the listing was private.
+        pub is_private: bool,
+        /// Type of listing. `None` indicates the listing was a buyout.
+        pub listing_type: Option<ListingType>,
+        /// Creator of the listing.
+        #[serde(with = "address_fromjson")]
+        pub maker: Address,
+        /// Token used for payment.
+        pub payment_token: PaymentToken,
+        /// Number of items bought. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+        /// Purchase price. See `payment_token` for the actual value of each unit.
+        #[serde(with = "u256_fromstr_radix_10")]
+        pub sale_price: U256,
+        /// Buyer/winner of the listing.
+        #[serde(with = "address_fromjson")]
+        pub taker: Address,
+        /// Transaction for the purchase.
+        pub transaction: Transaction,
+    }
+}
 
-    /// Timestamp of when the item was sold.
-    pub event_timestamp: DateTime<Utc>,
-    /// Timestamp of when the listing was closed.
-    pub closing_date: DateTime<Utc>,
-    /// Whether the listing was private.
-    pub is_private: bool,
-    /// Type of listing. `None` indicates the listing was a buyout.
-    pub listing_type: Option<ListingType>,
-    /// Creator of the listing.
-    #[serde(with = "address_fromjson")]
-    pub maker: Address,
-    /// Token used for payment.
-    pub payment_token: PaymentToken,
-    /// Number of items bought. This is always `1` for ERC-721 tokens.
-    pub quantity: u64,
-    /// Purchase price. See `payment_token` for the actual value of each unit.
-    #[serde(with = "u256_fromstr_radix_10")]
-    pub sale_price: U256,
-    /// Buyer/winner of the listing.
-    #[serde(with = "address_fromjson")]
-    pub taker: Address,
-    /// Transaction for the purchase.
-    pub transaction: Transaction,
+impl ItemSoldData {
+    /// Price of a single item, i.e. `sale_price` divided by `quantity`, as with
+    /// [`ItemListedData::unit_price`].
+    pub fn unit_price(&self) -> Option<U256> {
+        unit_price(self.sale_price, self.quantity)
+    }
+
+    /// Price of a single item in whole units of `payment_token`, as with
+    /// [`ItemListedData::unit_price_decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn unit_price_decimal(&self) -> Option<Decimal> {
+        unit_price_decimal(&self.payment_token, self.sale_price, self.quantity)
+    }
 }
 
-/// Payload data for [`Payload::ItemTransferred`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemTransferredData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+payload_data! {
+    /// Payload data for [`Payload::ItemTransferred`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemTransferredData {
+        /// Timestamp of when the item was transferred.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Transaction of the transfer.
+        pub transaction: Transaction,
+        /// Address the item was transferred from.
+        #[serde(with = "address_fromjson")]
+        pub from_account: Address,
+        /// Address the item was transferred to.
+        #[serde(with = "address_fromjson")]
+        pub to_account: Address,
+        /// Number of items transferred. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+    }
+}
 
-    /// Timestamp of when the item was transferred.
-    pub event_timestamp: DateTime<Utc>,
-    /// Transaction of the transfer.
-    pub transaction: Transaction,
-    /// Address the item was transferred from.
-    #[serde(with = "address_fromjson")]
-    pub from_account: Address,
-    /// Address the item was transferred to.
-    #[serde(with = "address_fromjson")]
-    pub to_account: Address,
-    /// Number of items transferred. This is always `1` for ERC-721 tokens.
-    pub quantity: u64,
+impl ItemTransferredData {
+    /// Returns whether the transfer is a mint, i.e. it is from the zero address.
+    pub fn is_mint(&self) -> bool {
+        self.from_account.is_zero()
+    }
+
+    /// Returns whether the transfer is a burn, i.e. it is to the zero address or to
+    /// the conventional dead address `0x000000000000000000000000000000000000dEaD`.
+    pub fn is_burn(&self) -> bool {
+        const DEAD: Address = address("000000000000000000000000000000000000dead");
+        self.to_account.is_zero() || self.to_account == DEAD
+    }
 }
 
-/// Payload data for [`Payload::ItemMetadataUpdated`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemMetadataUpdatedData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+payload_data! {
+    /// Payload data for [`Payload::ItemMetadataUpdated`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemMetadataUpdatedData {
+        /// New name.
+        pub name: Option<String>,
+        /// New description.
+        pub description: Option<String>,
+        /// New image URL. `None` if it is missing or not an absolute URL.
+        #[serde(default, with = "url_lenient")]
+        pub image_url: Option<Url>,
+        /// New cached preview URL. `None` if it is missing or not an absolute URL.
+        #[serde(default, with = "url_lenient")]
+        pub image_preview_url: Option<Url>,
+        /// New animation URL. `None` if it is missing or not an absolute URL.
+        #[serde(default, with = "url_lenient")]
+        pub animation_url: Option<Url>,
+        /// New external URL. This is shown on the item's page.
+        /// `None` if it is missing or not an absolute URL.
+        #[serde(default, with = "url_lenient")]
+        pub external_url: Option<Url>,
+        /// New background color.
+        pub background_color: Option<String>,
+        /// New URL to metadata. `None` if it is missing or not an absolute URL.
+        #[serde(default, with = "url_lenient")]
+        pub metadata_url: Option<Url>,
+        /// New traits. Traits that cannot be decoded are skipped, and `None` indicates
+        /// the traits are missing or not a list.
+        #[serde(default, with = "vec_lenient")]
+        pub traits: Option<Vec<Trait>>,
+    }
+}
 
-    /// New name.
-    pub name: Option<String>,
-    /// New description.
-    pub description: Option<String>,
-    /// New cached preview URL.
-    pub image_preview_url: Option<Url>,
-    /// New animation URL.
-    pub animation_url: Option<Url>,
-    /// New background color.
-    pub background_color: Option<String>,
-    /// New URL to metadata
-    pub metadata_url: Option<Url>,
-    /// New traits. This appears to be bugged for now, and will always be empty.
-    #[serde(default)]
-    pub traits: Vec<serde_json::Value>,
+impl ItemMetadataUpdatedData {
+    /// `background_color` parsed as an [`Rgb`] color.
+    ///
+    /// `None` is returned if there is no background color or it is not a valid hex color,
+    /// in which case the raw value is still available in `background_color`.
+    pub fn background_rgb(&self) -> Option<Rgb> {
+        self.background_color.as_deref()?.parse().ok()
+    }
+
+    /// Compares this update against a previous update of the same item.
+    ///
+    /// Traits are matched by `trait_type` and `value`; changes to `trait_count` are ignored.
+    /// Missing traits are treated as an empty list.
+    pub fn diff(&self, previous: &ItemMetadataUpdatedData) -> MetadataDiff {
+        let mut fields = self.changed_from(&Metadata {
+            name: previous.name.clone(),
+            description: previous.description.clone(),
+            image_url: previous.image_url.clone(),
+            animation_url: previous.animation_url.clone(),
+            metadata_url: previous.metadata_url.clone(),
+        });
+        if self.image_preview_url != previous.image_preview_url {
+            fields.push(MetadataField::ImagePreviewUrl);
+        }
+        if self.external_url != previous.external_url {
+            fields.push(MetadataField::ExternalUrl);
+        }
+        if self.background_color != previous.background_color {
+            fields.push(MetadataField::BackgroundColor);
+        }
+
+        let traits = self.traits.as_deref().unwrap_or_default();
+        let previous_traits = previous.traits.as_deref().unwrap_or_default();
+        let missing_from = |traits: &[Trait], t: &Trait| {
+            !traits
+                .iter()
+                .any(|other| other.trait_type == t.trait_type && other.value == t.value)
+        };
+        let added_traits: Vec<Trait> = traits
+            .iter()
+            .filter(|t| missing_from(previous_traits, t))
+            .cloned()
+            .collect();
+        let removed_traits: Vec<Trait> = previous_traits
+            .iter()
+            .filter(|t| missing_from(traits, t))
+            .cloned()
+            .collect();
+        if !added_traits.is_empty() || !removed_traits.is_empty() {
+            fields.push(MetadataField::Traits);
+        }
+
+        MetadataDiff {
+            fields,
+            added_traits,
+            removed_traits,
+        }
+    }
+
+    /// Fields which differ from previously known [`Metadata`] of the item,
+    /// e.g. `context.item.metadata` of an earlier event.
+    ///
+    /// Only the fields present in [`Metadata`] are compared.
+    pub fn changed_from(&self, previous: &Metadata) -> Vec<MetadataField> {
+        let mut fields = Vec::new();
+        if self.name != previous.name {
+            fields.push(MetadataField::Name);
+        }
+        if self.description != previous.description {
+            fields.push(MetadataField::Description);
+        }
+        if self.image_url != previous.image_url {
+            fields.push(MetadataField::ImageUrl);
+        }
+        if self.animation_url != previous.animation_url {
+            fields.push(MetadataField::AnimationUrl);
+        }
+        if self.metadata_url != previous.metadata_url {
+            fields.push(MetadataField::MetadataUrl);
+        }
+        fields
+    }
 }
 
-/// Payload data for [`Payload::ItemCancelled`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemCancelledData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+/// A color, such as the background color of an item.
+///
+/// Parsing is lenient: the leading `#` is optional, surrounding whitespace is ignored,
+/// and both 3 and 6 digit hex colors are accepted in either case.
+/// ```
+/// # use opensea_stream::schema::Rgb;
+/// assert_eq!("#1B1F3A".parse(), Ok(Rgb { r: 0x1b, g: 0x1f, b: 0x3a }));
+/// assert_eq!("fff".parse(), Ok(Rgb { r: 0xff, g: 0xff, b: 0xff }));
+/// assert_eq!(Rgb { r: 0x1b, g: 0x1f, b: 0x3a }.to_string(), "#1b1f3a");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    /// Red component.
+    pub r: u8,
+    /// Green component.
+    pub g: u8,
+    /// Blue component.
+    pub b: u8,
+}
 
-    /// Timestamp of when the listing was cancelled.
-    pub event_timestamp: DateTime<Utc>,
-    /// Type of listing. `None` indicates the listing would've been a buyout.
-    pub listing_type: Option<ListingType>,
-    /// Token accepted for payment.
-    pub payment_token: PaymentToken,
-    /// Number of items in listing. This is always `1` for ERC-721 tokens.
-    pub quantity: u64,
-    /// Transaction for the cancellation.
-    pub transaction: Transaction,
+impl FromStr for Rgb {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(());
+        }
+
+        let component = |i: usize, len: usize| u8::from_str_radix(&hex[i * len..(i + 1) * len], 16);
+        match hex.len() {
+            6 => Ok(Rgb {
+                r: component(0, 2).map_err(|_| ())?,
+                g: component(1, 2).map_err(|_| ())?,
+                b: component(2, 2).map_err(|_| ())?,
+            }),
+            3 => Ok(Rgb {
+                r: component(0, 1).map_err(|_| ())? * 0x11,
+                g: component(1, 1).map_err(|_| ())? * 0x11,
+                b: component(2, 1).map_err(|_| ())? * 0x11,
+            }),
+            _ => Err(()),
+        }
+    }
 }
 
-/// Payload data for [`Payload::ItemReceivedOffer`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemReceivedOfferData {
-    /// Context
-    #[serde(flatten)]
-    pub context: Context,
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
 
-    /// Timestamp of when the offer was received.
-    pub event_timestamp: DateTime<Utc>,
-    /// Offer price. See `payment_token` for the actual value of each unit.
-    #[serde(with = "u256_fromstr_radix_10")]
-    pub base_price: U256,
-    /// Timestamp of when the offer was created.
-    pub created_date: DateTime<Utc>,
-    /// Timestamp of when the offer will expire.
-    pub expiration_date: DateTime<Utc>,
-    /// Creator of the offer.
-    #[serde(with = "address_fromjson")]
-    pub maker: Address,
-    /// Token offered for payment.
-    pub payment_token: PaymentToken,
-    /// Number of items on the offer. This is always `1` for ERC-721 tokens.
-    pub quantity: u64,
-    /// Taker of the offer.
-    #[serde(with = "address_fromjson_opt", default)]
-    pub taker: Option<Address>,
+/// A field of [`ItemMetadataUpdatedData`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MetadataField {
+    /// `name`
+    Name,
+    /// `description`
+    Description,
+    /// `image_url`
+    ImageUrl,
+    /// `image_preview_url`
+    ImagePreviewUrl,
+    /// `animation_url`
+    AnimationUrl,
+    /// `external_url`
+    ExternalUrl,
+    /// `background_color`
+    BackgroundColor,
+    /// `metadata_url`
+    MetadataUrl,
+    /// `traits`
+    Traits,
 }
 
-/// Payload data for [`Payload::ItemReceivedBid`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ItemReceivedBidData {
+/// Changes between two metadata updates, from [`ItemMetadataUpdatedData::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataDiff {
+    /// Fields that changed.
+    pub fields: Vec<MetadataField>,
+    /// Traits present only in the newer update.
+    pub added_traits: Vec<Trait>,
+    /// Traits present only in the older update.
+    pub removed_traits: Vec<Trait>,
+}
+
+impl MetadataDiff {
+    /// Returns whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// A trait (attribute) of an item.
+///
+/// See [metadata standards](https://docs.opensea.io/docs/metadata-standards#attributes) for how these are defined.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Trait {
+    /// Name of the trait.
+    pub trait_type: Option<String>,
+    /// Value of the trait.
+    pub value: Option<TraitValue>,
+    /// How the trait is displayed, e.g. `number`, `boost_percentage` or `date`. `None` indicates a plain string trait.
+    pub display_type: Option<String>,
+    /// Maximum value of a numeric trait.
+    #[serde(default, with = "number_lenient")]
+    pub max_value: Option<serde_json::Number>,
+    /// Number of items in the collection with this trait.
+    #[serde(default, with = "number_lenient")]
+    pub trait_count: Option<serde_json::Number>,
+}
+
+/// Value of a [`Trait`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum TraitValue {
+    /// String value.
+    String(String),
+    /// Numeric value.
+    Number(serde_json::Number),
+    /// Boolean value.
+    Bool(bool),
+    /// Any other value, e.g. an array or an object.
+    Other(serde_json::Value),
+}
+
+impl fmt::Display for TraitValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraitValue::String(s) => write!(f, "{}", s),
+            TraitValue::Number(n) => write!(f, "{}", n),
+            TraitValue::Bool(b) => write!(f, "{}", b),
+            TraitValue::Other(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+payload_data! {
+    /// Payload data for [`Payload::ItemCancelled`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemCancelledData {
+        /// Timestamp of when the listing was cancelled.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Type of listing. `None` indicates the listing would've been a buyout.
+        pub listing_type: Option<ListingType>,
+        /// Token accepted for payment.
+        pub payment_token: PaymentToken,
+        /// Number of items in listing. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+        /// Transaction for the cancellation.
+        pub transaction: Transaction,
+    }
+}
+
+payload_data! {
+    /// Payload data for [`Payload::ItemReceivedOffer`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemReceivedOfferData {
+        /// Timestamp of when the offer was received.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Offer price. See `payment_token` for the actual value of each unit.
+        #[serde(with = "u256_fromstr_radix_10")]
+        pub base_price: U256,
+        /// Timestamp of when the offer was created.
+        #[serde(with = "datetime")]
+        pub created_date: DateTime<Utc>,
+        /// Timestamp of when the offer will expire. `None` indicates the offer does not expire.
+        #[serde(with = "expiration_date", default)]
+        pub expiration_date: Option<DateTime<Utc>>,
+        /// Creator of the offer.
+        #[serde(with = "address_fromjson")]
+        pub maker: Address,
+        /// Token offered for payment.
+        pub payment_token: PaymentToken,
+        /// Number of items on the offer. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+        /// Taker of the offer.
+        #[serde(with = "address_fromjson_opt", default)]
+        pub taker: Option<Address>,
+    }
+}
+
+impl ItemReceivedOfferData {
+    /// Price of a single item, i.e. `base_price` divided by `quantity`, as with
+    /// [`ItemListedData::unit_price`].
+    pub fn unit_price(&self) -> Option<U256> {
+        unit_price(self.base_price, self.quantity)
+    }
+
+    /// Price of a single item in whole units of `payment_token`, as with
+    /// [`ItemListedData::unit_price_decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn unit_price_decimal(&self) -> Option<Decimal> {
+        unit_price_decimal(&self.payment_token, self.base_price, self.quantity)
+    }
+
+    /// Returns whether the offer has expired at `now`, as with [`ItemListedData::is_expired`].
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        is_expired(self.expiration_date, now)
+    }
+
+    /// Returns whether the offer has been created and has not expired at `now`, as with
+    /// [`ItemListedData::is_active`].
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        is_active(self.created_date, self.expiration_date, now)
+    }
+}
+
+payload_data! {
+    /// Payload data for [`Payload::ItemReceivedBid`].
+    #[derive(Serialize, Debug, Clone)]
+    pub struct ItemReceivedBidData {
+        /// Timestamp of when the bid was received.
+        #[serde(with = "datetime")]
+        pub event_timestamp: DateTime<Utc>,
+        /// Bid price. See `payment_token` for the actual value of each unit.
+        #[serde(with = "u256_fromstr_radix_10")]
+        pub base_price: U256,
+        /// Timestamp of when the bid was created.
+        #[serde(with = "datetime")]
+        pub created_date: DateTime<Utc>,
+        /// Timestamp of when the bid will expire. `None` indicates the bid does not expire.
+        #[serde(with = "expiration_date", default)]
+        pub expiration_date: Option<DateTime<Utc>>,
+        /// Creator of the bid.
+        #[serde(with = "address_fromjson")]
+        pub maker: Address,
+        /// Token offered for payment.
+        pub payment_token: PaymentToken,
+        /// Number of items on the offer. This is always `1` for ERC-721 tokens.
+        pub quantity: u64,
+        /// Taker of the bid.
+        #[serde(with = "address_fromjson_opt", default)]
+        pub taker: Option<Address>,
+    }
+}
+
+impl ItemReceivedBidData {
+    /// Price of a single item, i.e. `base_price` divided by `quantity`, as with
+    /// [`ItemListedData::unit_price`].
+    pub fn unit_price(&self) -> Option<U256> {
+        unit_price(self.base_price, self.quantity)
+    }
+
+    /// Price of a single item in whole units of `payment_token`, as with
+    /// [`ItemListedData::unit_price_decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn unit_price_decimal(&self) -> Option<Decimal> {
+        unit_price_decimal(&self.payment_token, self.base_price, self.quantity)
+    }
+
+    /// Returns whether the bid has expired at `now`, as with [`ItemListedData::is_expired`].
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        is_expired(self.expiration_date, now)
+    }
+
+    /// Returns whether the bid has been created and has not expired at `now`, as with
+    /// [`ItemListedData::is_active`].
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        is_active(self.created_date, self.expiration_date, now)
+    }
+}
+
+/// Price of a single item out of `quantity` items costing `price` in total, rounded down.
+fn unit_price(price: U256, quantity: u64) -> Option<U256> {
+    price.checked_div(U256::from(quantity))
+}
+
+/// [`unit_price`] in whole units of `token`.
+#[cfg(feature = "rust_decimal")]
+fn unit_price_decimal(token: &PaymentToken, price: U256, quantity: u64) -> Option<Decimal> {
+    token
+        .to_decimal(price)?
+        .checked_div(Decimal::from(quantity))
+}
+
+/// Whether an order expiring at `expiration_date` has expired at `now`.
+fn is_expired(expiration_date: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    expiration_date.is_some_and(|expiration| expiration <= now)
+}
+
+/// Whether an order created at `start` and expiring at `expiration_date` is active at `now`.
+fn is_active(
+    start: DateTime<Utc>,
+    expiration_date: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    start <= now && !is_expired(expiration_date, now)
+}
+
+/// Side of a [`DemandOrder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DemandSide {
+    /// An offer on an item, from [`Payload::ItemReceivedOffer`].
+    Offer,
+    /// A bid on an auctioned item, from [`Payload::ItemReceivedBid`].
+    Bid,
+}
+
+/// An order to buy an item, normalized from either an offer or a bid.
+///
+/// This lets order books and alerts handle offers and bids as one type.
+/// ```
+/// # use opensea_stream::schema::{DemandOrder, Payload};
+/// # fn handle(payload: Payload) {
+/// let order = match payload {
+///     Payload::ItemReceivedOffer(offer) => DemandOrder::from(offer),
+///     Payload::ItemReceivedBid(bid) => DemandOrder::from(bid),
+///     _ => return,
+/// };
+/// println!("{:?} of {} on {:?}", order.side, order.base_price, order.context.item.nft_id);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DemandOrder {
+    /// Whether this was an offer or a bid.
+    pub side: DemandSide,
     /// Context
-    #[serde(flatten)]
     pub context: Context,
-
-    /// Timestamp of when the bid was received.
+    /// Timestamp of when the order was received.
     pub event_timestamp: DateTime<Utc>,
-    /// Bid price. See `payment_token` for the actual value of each unit.
-    #[serde(with = "u256_fromstr_radix_10")]
+    /// Order price. See `payment_token` for the actual value of each unit.
     pub base_price: U256,
-    /// Timestamp of when the bid was created.
+    /// Timestamp of when the order was created.
     pub created_date: DateTime<Utc>,
-    /// Timestamp of when the bid will expire.
-    pub expiration_date: DateTime<Utc>,
-    /// Creator of the bid.
-    #[serde(with = "address_fromjson")]
+    /// Timestamp of when the order will expire. `None` indicates the order does not expire.
+    pub expiration_date: Option<DateTime<Utc>>,
+    /// Creator of the order.
     pub maker: Address,
     /// Token offered for payment.
     pub payment_token: PaymentToken,
-    /// Number of items on the offer. This is always `1` for ERC-721 tokens.
+    /// Number of items wanted. This is always `1` for ERC-721 tokens.
     pub quantity: u64,
-    /// Taker of the bid.
-    #[serde(with = "address_fromjson_opt", default)]
+    /// Taker of the order.
     pub taker: Option<Address>,
 }
 
+impl DemandOrder {
+    /// Price of a single item, i.e. `base_price` divided by `quantity`, as with
+    /// [`ItemListedData::unit_price`].
+    pub fn unit_price(&self) -> Option<U256> {
+        unit_price(self.base_price, self.quantity)
+    }
+
+    /// Price of a single item in whole units of `payment_token`, as with
+    /// [`ItemListedData::unit_price_decimal`].
+    #[cfg(feature = "rust_decimal")]
+    pub fn unit_price_decimal(&self) -> Option<Decimal> {
+        unit_price_decimal(&self.payment_token, self.base_price, self.quantity)
+    }
+
+    /// Returns whether the order has expired at `now`, as with [`ItemListedData::is_expired`].
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        is_expired(self.expiration_date, now)
+    }
+
+    /// Returns whether the order has been created and has not expired at `now`, as with
+    /// [`ItemListedData::is_active`].
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        is_active(self.created_date, self.expiration_date, now)
+    }
+}
+
+impl From<ItemReceivedOfferData> for DemandOrder {
+    fn from(offer: ItemReceivedOfferData) -> Self {
+        DemandOrder {
+            side: DemandSide::Offer,
+            context: offer.context,
+            event_timestamp: offer.event_timestamp,
+            base_price: offer.base_price,
+            created_date: offer.created_date,
+            expiration_date: offer.expiration_date,
+            maker: offer.maker,
+            payment_token: offer.payment_token,
+            quantity: offer.quantity,
+            taker: offer.taker,
+        }
+    }
+}
+
+impl From<ItemReceivedBidData> for DemandOrder {
+    fn from(bid: ItemReceivedBidData) -> Self {
+        DemandOrder {
+            side: DemandSide::Bid,
+            context: bid.context,
+            event_timestamp: bid.event_timestamp,
+            base_price: bid.base_price,
+            created_date: bid.created_date,
+            expiration_date: bid.expiration_date,
+            maker: bid.maker,
+            payment_token: bid.payment_token,
+            quantity: bid.quantity,
+            taker: bid.taker,
+        }
+    }
+}
+
 /// Auctioning system used by the listing.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
@@ -463,61 +1508,13 @@ impl fmt::Display for ListingType {
     }
 }
 
-mod address_fromjson {
-    use ethers_core::abi::Address;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
-
-    #[derive(Serialize, Deserialize)]
-    struct Inner {
-        address: Address,
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Address, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        Deserialize::deserialize(deserializer).map(|v: Inner| v.address)
-    }
-
-    pub fn serialize<S>(value: &Address, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        Inner { address: *value }.serialize(serializer)
-    }
-}
-
-mod address_fromjson_opt {
-    use ethers_core::abi::Address;
-    use serde::{Deserialize, Deserializer, Serialize, Serializer};
-
-    #[derive(Serialize, Deserialize)]
-    struct Inner {
-        address: Address,
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Address>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let inner: Option<Inner> = Deserialize::deserialize(deserializer)?;
-        Ok(inner.map(|i| i.address))
-    }
-
-    pub fn serialize<S>(value: &Option<Address>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        value.map(|v| Inner { address: v }).serialize(serializer)
-    }
-}
-
 /// Details of a transaction
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Transaction {
     /// Transaction hash
     pub hash: H256,
     /// Timestamp of transaction
+    #[serde(with = "datetime")]
     pub timestamp: DateTime<Utc>,
 }
 
@@ -540,68 +1537,193 @@ pub struct PaymentToken {
     pub usd_price: f64,
 }
 
-// h/t: meetmangukiya (https://gist.github.com/meetmangukiya/40cad17bcb7d3196d33b072a3500fac7)
-mod u256_fromstr_radix_10 {
-    use super::*;
-    use serde::{de::Visitor, Deserializer, Serializer};
-    use std::fmt;
+impl PaymentToken {
+    /// Converts an amount in the smallest unit of this token (e.g. wei) to whole units (e.g. ETH).
+    ///
+    /// `None` is returned if the amount cannot be represented as a [`Decimal`].
+    /// ```
+    /// # use ethers_core::types::{Address, U256};
+    /// # use opensea_stream::schema::PaymentToken;
+    /// # use rust_decimal::Decimal;
+    /// let ether = PaymentToken {
+    ///     address: Address::zero(),
+    ///     decimals: 18,
+    ///     eth_price: 1.0,
+    ///     name: "Ether".to_string(),
+    ///     symbol: "ETH".to_string(),
+    ///     usd_price: 1717.36,
+    /// };
+    ///
+    /// // 0.15 ETH
+    /// let amount = U256::from(150_000_000_000_000_000u64);
+    /// assert_eq!(ether.to_decimal(amount), Some(Decimal::new(15, 2)));
+    /// ```
+    #[cfg(feature = "rust_decimal")]
+    pub fn to_decimal(&self, amount: U256) -> Option<Decimal> {
+        if amount.bits() > 96 {
+            return None;
+        }
+        let scale = u32::try_from(self.decimals).ok()?;
+        Decimal::try_from_i128_with_scale(amount.as_u128() as i128, scale).ok()
+    }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct Helper;
+    /// Looks up this token in the registry of [`WellKnownToken`]s.
+    ///
+    /// OpenSea does not send the chain of a payment token, so the chain of the item
+    /// (see [`Item::chain`]) should be passed in.
+    pub fn well_known(&self, chain: Chain) -> Option<WellKnownToken> {
+        WellKnownToken::from_address(chain, self.address)
+    }
 
-        impl<'de> Visitor<'de> for Helper {
-            type Value = U256;
+    /// Symbol of the token.
+    pub fn token_symbol(&self) -> TokenSymbol {
+        TokenSymbol::from(self.symbol.as_str())
+    }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a string")
-            }
+    /// Whether the token is ETH or WETH.
+    pub fn is_eth_equivalent(&self) -> bool {
+        matches!(self.token_symbol(), TokenSymbol::Eth | TokenSymbol::Weth)
+    }
+}
 
-            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                U256::from_dec_str(value).map_err(serde::de::Error::custom)
-            }
-        }
+/// Symbol of a [`PaymentToken`].
+///
+/// Symbols are matched case-insensitively; anything unrecognised is kept in [`TokenSymbol::Other`].
+/// Symbols are chosen by the token contract, so a recognised symbol does not guarantee the token is
+/// legitimate. Use [`PaymentToken::well_known`] to check the address as well.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TokenSymbol {
+    /// `ETH`
+    Eth,
+    /// `WETH`
+    Weth,
+    /// `USDC`
+    Usdc,
+    /// `DAI`
+    Dai,
+    /// Any other symbol.
+    Other(String),
+}
 
-        deserializer.deserialize_str(Helper)
+impl From<&str> for TokenSymbol {
+    fn from(symbol: &str) -> Self {
+        [
+            ("ETH", TokenSymbol::Eth),
+            ("WETH", TokenSymbol::Weth),
+            ("USDC", TokenSymbol::Usdc),
+            ("DAI", TokenSymbol::Dai),
+        ]
+        .into_iter()
+        .find(|(s, _)| s.eq_ignore_ascii_case(symbol))
+        .map(|(_, v)| v)
+        .unwrap_or_else(|| TokenSymbol::Other(symbol.to_owned()))
     }
+}
 
-    pub fn serialize<S>(value: &U256, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.collect_str(&value)
+impl fmt::Display for TokenSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TokenSymbol::Eth => "ETH",
+                TokenSymbol::Weth => "WETH",
+                TokenSymbol::Usdc => "USDC",
+                TokenSymbol::Dai => "DAI",
+                TokenSymbol::Other(s) => s,
+            }
+        )
     }
 }
 
-mod f64_fromstring {
-    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+/// Commonly used payment tokens.
+///
+/// Native currencies use the zero address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WellKnownToken {
+    /// Ether.
+    Eth,
+    /// Wrapped Ether.
+    Weth,
+    /// USD Coin.
+    Usdc,
+    /// Dai.
+    Dai,
+    /// Polygon's native currency.
+    Matic,
+    /// Klaytn's native currency.
+    Klay,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringFloat {
-            Str(String),
-            F64(f64),
-        }
+#[rustfmt::skip]
+const WELL_KNOWN_TOKENS: &[(Chain, WellKnownToken, Address)] = &[
+    (Chain::Ethereum, WellKnownToken::Eth, address("0000000000000000000000000000000000000000")),
+    (Chain::Ethereum, WellKnownToken::Weth, address("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")),
+    (Chain::Ethereum, WellKnownToken::Usdc, address("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")),
+    (Chain::Ethereum, WellKnownToken::Dai, address("6b175474e89094c44da98b954eedeac495271d0f")),
+    (Chain::Polygon, WellKnownToken::Matic, address("0000000000000000000000000000000000000000")),
+    (Chain::Polygon, WellKnownToken::Weth, address("7ceb23fd6bc0add59e62ac25578270cff1b9f619")),
+    (Chain::Polygon, WellKnownToken::Usdc, address("2791bca1f2de4661ed88a44c7a59e0aa5bc81b5d")),
+    (Chain::Polygon, WellKnownToken::Dai, address("8f3cf7ad23cd3cadbd9735aff958023239c6a063")),
+    (Chain::Klaytn, WellKnownToken::Klay, address("0000000000000000000000000000000000000000")),
+    (Chain::Base, WellKnownToken::Eth, address("0000000000000000000000000000000000000000")),
+    (Chain::Base, WellKnownToken::Weth, address("4200000000000000000000000000000000000006")),
+    (Chain::Base, WellKnownToken::Usdc, address("833589fcd6edb6e08f4c7c32d4f71b54bda02913")),
+    (Chain::Goerli, WellKnownToken::Eth, address("0000000000000000000000000000000000000000")),
+    (Chain::Goerli, WellKnownToken::Weth, address("b4fbf271143f4fbf7b91a5ded31805e42b2208d6")),
+    (Chain::Mumbai, WellKnownToken::Matic, address("0000000000000000000000000000000000000000")),
+    (Chain::Mumbai, WellKnownToken::Weth, address("a6fa4fb5f76172d178d61b04b0ecd319c5d1c0aa")),
+    (Chain::Baobab, WellKnownToken::Klay, address("0000000000000000000000000000000000000000")),
+];
+
+impl WellKnownToken {
+    /// Finds the token deployed at `address` on `chain`.
+    /// ```
+    /// # use opensea_stream::schema::{Chain, WellKnownToken};
+    /// let weth = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".parse()?;
+    /// assert_eq!(
+    ///     WellKnownToken::from_address(Chain::Ethereum, weth),
+    ///     Some(WellKnownToken::Weth)
+    /// );
+    /// assert_eq!(WellKnownToken::from_address(Chain::Polygon, weth), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_address(chain: Chain, address: Address) -> Option<Self> {
+        WELL_KNOWN_TOKENS
+            .iter()
+            .find(|(c, _, a)| *c == chain && *a == address)
+            .map(|(_, token, _)| *token)
+    }
 
-        match StringFloat::deserialize(deserializer)? {
-            StringFloat::Str(s) => s.parse().map_err(D::Error::custom),
-            StringFloat::F64(f) => Ok(f),
+    /// Address of the token on `chain`, if it is deployed there.
+    pub fn address(&self, chain: Chain) -> Option<Address> {
+        WELL_KNOWN_TOKENS
+            .iter()
+            .find(|(c, token, _)| *c == chain && token == self)
+            .map(|(_, _, address)| *address)
+    }
+}
+
+/// Parses a 40 character hex string (without the `0x` prefix) into an [`Address`] at compile time.
+const fn address(hex: &str) -> Address {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            _ => panic!("invalid hex character"),
         }
     }
 
-    pub fn serialize<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        value.to_string().serialize(serializer)
+    let hex = hex.as_bytes();
+    assert!(hex.len() == 40, "address must be 40 hex characters");
+
+    let mut bytes = [0u8; 20];
+    let mut i = 0;
+    while i < 20 {
+        bytes[i] = nibble(hex[2 * i]) << 4 | nibble(hex[2 * i + 1]);
+        i += 1;
     }
+    H160(bytes)
 }
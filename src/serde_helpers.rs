@@ -0,0 +1,505 @@
+//! These can be used with `#[serde(with = "...")]`
+//! when defining custom payload types for [`subscribe_to_with`](crate::subscribe_to_with).
+//! ```
+//! use ethers_core::types::{Address, U256};
+//! use opensea_stream::serde_helpers::{address_fromjson, u256_fromstr_radix_10};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Listing {
+//!     #[serde(with = "u256_fromstr_radix_10")]
+//!     base_price: U256,
+//!     #[serde(with = "address_fromjson")]
+//!     maker: Address,
+//! }
+//!
+//! let listing: Listing = serde_json::from_str(
+//!     r#"{
+//!         "base_price": "120000000000000000",
+//!         "maker": { "address": "0xdaeeb975729fae923d5a4fd12aabfe228f219e9c" }
+//!     }"#,
+//! )?;
+//! assert_eq!(listing.base_price, U256::exp10(17) * 12 / 10);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+/// Visits an address wrapped in an object, ignoring any other keys of the object.
+struct AddressVisitor;
+
+impl<'de> serde::de::Visitor<'de> for AddressVisitor {
+    type Value = ethers_core::abi::Address;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an object with an address")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Address,
+            #[serde(other)]
+            Other,
+        }
+
+        let mut address = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Address => address = Some(map.next_value()?),
+                Field::Other => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        address.ok_or_else(|| serde::de::Error::missing_field("address"))
+    }
+}
+
+/// An [`Address`](ethers_core::abi::Address) wrapped in an object, e.g. `{"address": "0x..."}`.
+pub mod address_fromjson {
+    use super::AddressVisitor;
+    use ethers_core::abi::Address;
+    use serde::{ser::SerializeMap, Deserializer, Serializer};
+
+    /// Deserializes an address from `{"address": "0x..."}`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Address, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(AddressVisitor)
+    }
+
+    /// Serializes an address into `{"address": "0x..."}`.
+    pub fn serialize<S>(value: &Address, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry("address", value)?;
+        map.end()
+    }
+}
+
+/// An optional [`Address`](ethers_core::abi::Address) wrapped in an object, e.g. `{"address": "0x..."}` or `null`.
+///
+/// Use with `#[serde(default)]` to also accept a missing field.
+pub mod address_fromjson_opt {
+    use super::AddressVisitor;
+    use ethers_core::abi::Address;
+    use serde::{de::Visitor, Deserializer, Serializer};
+    use std::fmt;
+
+    /// Deserializes an address from `{"address": "0x..."}` or `null`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Address>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = Option<Address>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an object with an address or null")
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_map(AddressVisitor).map(Some)
+            }
+        }
+
+        deserializer.deserialize_option(Helper)
+    }
+
+    /// Serializes an address into `{"address": "0x..."}` or `null`.
+    pub fn serialize<S>(value: &Option<Address>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(address) => super::address_fromjson::serialize(address, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+// h/t: meetmangukiya (https://gist.github.com/meetmangukiya/40cad17bcb7d3196d33b072a3500fac7)
+/// A [`U256`](ethers_core::types::U256) encoded as a string of decimal digits, e.g. `"120000000000000000"`.
+pub mod u256_fromstr_radix_10 {
+    use ethers_core::types::U256;
+    use serde::{de::Visitor, Deserializer, Serializer};
+    use std::fmt;
+
+    /// Deserializes a `U256` from a string of decimal digits.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<U256, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = U256;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                U256::from_dec_str(value).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Helper)
+    }
+
+    /// Serializes a `U256` into a string of decimal digits.
+    pub fn serialize<S>(value: &U256, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value)
+    }
+}
+
+/// An `f64` encoded as either a number or a string, e.g. `"1717.36"`.
+pub mod f64_fromstring {
+    use serde::{
+        de::{Error, Visitor},
+        Deserializer, Serialize, Serializer,
+    };
+    use std::fmt;
+
+    /// Deserializes an `f64` from a number or a string containing a number.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = f64;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a float or a string containing a float")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(value)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(value as f64)
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(value as f64)
+            }
+        }
+
+        deserializer.deserialize_any(Helper)
+    }
+
+    /// Serializes an `f64` into a string.
+    pub fn serialize<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.to_string().serialize(serializer)
+    }
+}
+
+/// A UTC timestamp, accepting the variations seen in payloads.
+///
+/// RFC 3339 timestamps are accepted with any offset, with or without fractional seconds,
+/// and with either `T` or a space between the date and time. Timestamps without an offset
+/// are assumed to be in UTC.
+/// ```
+/// # use chrono::{DateTime, Utc};
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Timestamp(#[serde(with = "opensea_stream::serde_helpers::datetime")] DateTime<Utc>);
+///
+/// for variant in [
+///     r#""2022-09-12T08:26:46.000000+00:00""#,
+///     r#""2022-09-12T08:26:46Z""#,
+///     r#""2022-09-12T10:26:46+02:00""#,
+///     r#""2022-09-12T08:26:46+0000""#,
+///     r#""2022-09-12 08:26:46+00:00""#,
+///     r#""2022-09-12T08:26:46.000""#,
+///     r#""2022-09-12T08:26:46""#,
+///     r#""2022-09-12 08:26:46""#,
+/// ] {
+///     let Timestamp(timestamp) = serde_json::from_str(variant)?;
+///     assert_eq!(timestamp.to_rfc3339(), "2022-09-12T08:26:46+00:00");
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod datetime {
+    use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
+    use serde::{de::Visitor, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    fn parse(value: &str) -> Option<DateTime<Utc>> {
+        if let Ok(date) = value.parse::<DateTime<FixedOffset>>() {
+            return Some(date.with_timezone(&Utc));
+        }
+        if let Ok(date) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+            return Some(date.with_timezone(&Utc));
+        }
+        ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+            .map(|date| Utc.from_utc_datetime(&date))
+    }
+
+    /// Deserializes a timestamp, assuming UTC if it has no offset.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Helper;
+
+        impl<'de> Visitor<'de> for Helper {
+            type Value = DateTime<Utc>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a timestamp")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                parse(value).ok_or_else(|| E::custom(format!("invalid timestamp: {}", value)))
+            }
+        }
+
+        deserializer.deserialize_str(Helper)
+    }
+
+    /// Serializes a timestamp in RFC 3339.
+    pub fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// An optional expiration timestamp, normalizing sentinels for "never expires" to `None`.
+///
+/// `null`, timestamps at or before the Unix epoch, and timestamps in the year 3000 or later
+/// are all treated as no expiration. Timestamps are parsed as with [`datetime`].
+/// Use with `#[serde(default)]` to also accept a missing field.
+/// ```
+/// # use chrono::{DateTime, Utc};
+/// # use serde::{Deserialize, Serialize};
+/// # use serde_json::json;
+/// #[derive(Serialize, Deserialize)]
+/// struct Order {
+///     #[serde(default, with = "opensea_stream::serde_helpers::expiration_date")]
+///     expiration_date: Option<DateTime<Utc>>,
+/// }
+///
+/// for (input, output) in [
+///     (json!({ "expiration_date": null }), json!({ "expiration_date": null })),
+///     (json!({}), json!({ "expiration_date": null })),
+///     (
+///         json!({ "expiration_date": "1970-01-01T00:00:00Z" }),
+///         json!({ "expiration_date": null }),
+///     ),
+///     (
+///         json!({ "expiration_date": "3000-01-01T00:00:00Z" }),
+///         json!({ "expiration_date": null }),
+///     ),
+///     (
+///         json!({ "expiration_date": "2022-10-12T08:26:26.000000+00:00" }),
+///         json!({ "expiration_date": "2022-10-12T08:26:26Z" }),
+///     ),
+/// ] {
+///     let order: Order = serde_json::from_value(input)?;
+///     assert_eq!(serde_json::to_value(&order)?, output);
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod expiration_date {
+    use chrono::{DateTime, Datelike, Utc};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    struct Inner(#[serde(with = "super::datetime")] DateTime<Utc>);
+
+    /// Deserializes an expiration timestamp, returning `None` for sentinel values.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let date: Option<Inner> = Deserialize::deserialize(deserializer)?;
+        Ok(date
+            .map(|Inner(date)| date)
+            .filter(|date| date.timestamp() > 0 && date.year() < 3000))
+    }
+
+    /// Serializes an expiration timestamp, or `null` if there is none.
+    pub fn serialize<S>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// An optional [`Url`](url::Url) that is `None` unless the value is an absolute URL.
+///
+/// Free-form metadata often contains empty strings or URLs without a scheme, which would
+/// otherwise fail to decode the whole event.
+/// Use with `#[serde(default)]` to also accept a missing field.
+/// ```
+/// # use serde::Deserialize;
+/// # use url::Url;
+/// #[derive(Deserialize)]
+/// struct Link(#[serde(with = "opensea_stream::serde_helpers::url_lenient")] Option<Url>);
+///
+/// let Link(url) = serde_json::from_str(r#""https://example.com/5""#)?;
+/// assert_eq!(url.unwrap().as_str(), "https://example.com/5");
+/// for variant in [r#""""#, r#""www.example.com""#, "5", "null"] {
+///     let Link(url) = serde_json::from_str(variant)?;
+///     assert_eq!(url, None);
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod url_lenient {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+    use url::Url;
+
+    /// Deserializes a URL, returning `None` for anything that isn't an absolute URL.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Url>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Ok(value.as_str().and_then(|url| Url::parse(url).ok()))
+    }
+
+    /// Serializes a URL, or `null` if there is none.
+    pub fn serialize<S>(value: &Option<Url>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// An optional [`Number`](serde_json::Number) encoded as either a number or a string, e.g. `"10"`.
+///
+/// Anything else, including strings that aren't numbers, is treated as `None`.
+/// Use with `#[serde(default)]` to also accept a missing field.
+pub mod number_lenient {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::{Number, Value};
+
+    /// Deserializes a number from a number or a string containing a number.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Value::deserialize(deserializer)? {
+            Value::Number(number) => Some(number),
+            Value::String(number) => number.trim().parse().ok(),
+            _ => None,
+        })
+    }
+
+    /// Serializes a number, or `null` if there is none.
+    pub fn serialize<S>(value: &Option<Number>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+}
+
+/// An optional list which skips elements that fail to decode, and is `None` unless the value is an array.
+///
+/// Use with `#[serde(default)]` to also accept a missing field.
+/// ```
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Numbers(#[serde(with = "opensea_stream::serde_helpers::vec_lenient")] Option<Vec<u64>>);
+///
+/// let Numbers(numbers) = serde_json::from_str(r#"[1, null, "2", 3]"#)?;
+/// assert_eq!(numbers, Some(vec![1, 3]));
+/// let Numbers(numbers) = serde_json::from_str("{}")?;
+/// assert_eq!(numbers, None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub mod vec_lenient {
+    use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    /// Deserializes the elements of an array that decode successfully.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: DeserializeOwned,
+    {
+        Ok(match Value::deserialize(deserializer)? {
+            Value::Array(values) => Some(
+                values
+                    .into_iter()
+                    .filter_map(|value| T::deserialize(value).ok())
+                    .collect(),
+            ),
+            _ => None,
+        })
+    }
+
+    /// Serializes a list, or `null` if there is none.
+    pub fn serialize<S, T>(value: &Option<Vec<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        value.serialize(serializer)
+    }
+}
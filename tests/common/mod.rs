@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use opensea_stream::{
+    fixtures,
+    schema::{ItemTransferredData, Payload, StreamEvent},
+    Collection, Event,
+};
+use phyllo::message::{self, Message};
+use serde_json::Value;
+
+/// [`fixtures::ITEM_TRANSFERRED`] for token `id` of the same contract, using `quantity` to tell events apart.
+pub fn transfer(id: u64, quantity: u64) -> StreamEvent {
+    let mut event: StreamEvent = serde_json::from_str(fixtures::ITEM_TRANSFERRED).unwrap();
+    let data = transfer_data(&mut event);
+    data.context.item.nft_id.id = id.into();
+    data.quantity = quantity;
+    event
+}
+
+/// Payload data of an event built by [`transfer`].
+pub fn transfer_data(event: &mut StreamEvent) -> &mut ItemTransferredData {
+    match &mut event.payload {
+        Payload::ItemTransferred(data) => data,
+        _ => unreachable!(),
+    }
+}
+
+/// The `quantity` of an event built by [`transfer`].
+pub fn quantity(event: &StreamEvent) -> u64 {
+    match &event.payload {
+        Payload::ItemTransferred(data) => data.quantity,
+        _ => unreachable!(),
+    }
+}
+
+/// Wraps an event built by [`transfer`] in a broadcast message, as received from a subscription.
+pub fn message(event: StreamEvent) -> Message<Collection, Event, Value, StreamEvent> {
+    Message {
+        join_ref: None,
+        reference: None,
+        topic: Collection::Collection("sandbox-wearables".to_owned()),
+        event: message::Event::Event(Event::ItemTransferred),
+        payload: Some(message::Payload::Custom(event)),
+    }
+}
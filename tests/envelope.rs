@@ -0,0 +1,41 @@
+#![cfg(feature = "fixtures")]
+
+mod common;
+
+use chrono::{Duration, TimeZone, Utc};
+use common::{message, quantity, transfer};
+use opensea_stream::{clock::MockClock, envelope::EnvelopeReceiver};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+#[tokio::test]
+async fn counts_lagged_events() {
+    let start = Utc.timestamp_opt(1_662_971_206, 0).unwrap();
+    let clock = MockClock::new(start);
+    let (tx, rx) = broadcast::channel(1);
+    let mut receiver = EnvelopeReceiver::new(rx).clock(clock.clone());
+
+    tx.send(message(transfer(1234, 0))).unwrap();
+    let envelope = receiver.recv().await.unwrap();
+    assert_eq!((envelope.seq, envelope.missed), (0, 0));
+    assert_eq!(quantity(&envelope.event), 0);
+    assert_eq!(envelope.received_at, start);
+
+    // Only the last event fits in the channel, so the two before it are missed.
+    for n in 1..=3 {
+        tx.send(message(transfer(1234, n))).unwrap();
+    }
+    clock.advance(Duration::seconds(5));
+    let envelope = receiver.recv().await.unwrap();
+    assert_eq!((envelope.seq, envelope.missed), (3, 2));
+    assert_eq!(quantity(&envelope.event), 3);
+    assert_eq!(envelope.received_at, start + Duration::seconds(5));
+    assert_eq!(receiver.missed(), 2);
+
+    tx.send(message(transfer(1234, 4))).unwrap();
+    let envelope = receiver.recv().await.unwrap();
+    assert_eq!((envelope.seq, envelope.missed), (4, 0));
+    assert_eq!(receiver.missed(), 2);
+
+    drop(tx);
+    assert_eq!(receiver.recv().await.unwrap_err(), RecvError::Closed);
+}
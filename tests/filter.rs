@@ -0,0 +1,171 @@
+#![cfg(feature = "fixtures")]
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{transfer, transfer_data};
+use ethers_core::types::{Address, U256};
+use opensea_stream::{
+    clock::MockClock,
+    filter::{Filter, FilterStats},
+    fixtures,
+    schema::{Chain, Payload, StreamEvent, StreamEventHeader},
+    Event,
+};
+use std::time::Duration as StdDuration;
+
+const NOW: &str = "2022-09-12T10:15:41Z";
+
+fn now() -> DateTime<Utc> {
+    NOW.parse().unwrap()
+}
+
+/// A transfer from `slug` on `chain`, for an item whose NFT ID is on `network`.
+fn event(slug: &str, chain: Chain, network: Chain, sent_at: DateTime<Utc>) -> StreamEvent {
+    let mut event = transfer(1234, 1);
+    event.sent_at = sent_at;
+    let context = &mut transfer_data(&mut event).context;
+    context.collection = slug.into();
+    context.item.chain = chain;
+    context.item.nft_id.network = network;
+    event
+}
+
+fn sent_at(sent_at: DateTime<Utc>) -> StreamEvent {
+    event("wandernauts", Chain::Ethereum, Chain::Ethereum, sent_at)
+}
+
+fn on_chain(chain: Chain) -> StreamEvent {
+    event("wandernauts", chain, chain, now())
+}
+
+fn from_collection(slug: &str) -> StreamEvent {
+    event(slug, Chain::Ethereum, Chain::Ethereum, now())
+}
+
+#[test]
+fn rejects_stale_events() {
+    let clock = MockClock::new(now());
+    let mut filter = Filter::new()
+        .max_age(StdDuration::from_secs(30))
+        .clock(clock.clone());
+
+    assert!(filter.matches(&sent_at(now() - Duration::seconds(30))));
+    assert!(!filter.matches(&sent_at(now() - Duration::seconds(31))));
+    // Events from the future due to clock skew are never stale.
+    assert!(filter.matches(&sent_at(now() + Duration::minutes(5))));
+
+    // Ages are measured against the clock, not the time of the event.
+    let event = sent_at(now());
+    clock.advance(Duration::seconds(31));
+    assert!(!filter.matches(&event));
+
+    assert_eq!(filter.stats().stale, 2);
+}
+
+#[test]
+fn counts_rejected_chains() {
+    let mut filter = Filter::new().chains([Chain::Ethereum, Chain::Base]);
+
+    assert!(filter.matches(&on_chain(Chain::Ethereum)));
+    assert!(filter.matches(&on_chain(Chain::Base)));
+    assert!(!filter.matches(&on_chain(Chain::Polygon)));
+    assert!(!filter.matches(&on_chain(Chain::Polygon)));
+    assert!(!filter.matches(&on_chain(Chain::Klaytn)));
+
+    let stats = filter.stats();
+    assert_eq!(stats.chain.len(), 2);
+    assert_eq!(stats.chain[&Chain::Polygon], 2);
+    assert_eq!(stats.chain[&Chain::Klaytn], 1);
+
+    filter.reset_stats();
+    assert_eq!(filter.stats(), &FilterStats::default());
+}
+
+#[test]
+fn applies_collection_allow_and_block_lists() {
+    let mut allowed = Filter::new().collections(["wandernauts", "klay-critters"]);
+    assert!(allowed.matches(&from_collection("wandernauts")));
+    assert!(allowed.matches(&from_collection("klay-critters")));
+    assert!(!allowed.matches(&from_collection("sandbox-wearables")));
+    assert_eq!(allowed.stats().collection, 1);
+
+    let mut blocked = Filter::new().exclude_collections(["spam"]);
+    assert!(blocked.matches(&from_collection("wandernauts")));
+    assert!(!blocked.matches(&from_collection("spam")));
+    assert_eq!(blocked.stats().collection, 1);
+
+    // Exclusions take precedence over the allow list.
+    let mut both = Filter::new()
+        .collections(["wandernauts", "spam"])
+        .exclude_collections(["spam"]);
+    assert!(both.matches(&from_collection("wandernauts")));
+    assert!(!both.matches(&from_collection("spam")));
+    assert_eq!(both.stats().collection, 1);
+}
+
+#[test]
+fn rejects_inconsistent_chains() {
+    let mismatched = event("wandernauts", Chain::Ethereum, Chain::Polygon, now());
+
+    let mut lenient = Filter::new();
+    assert!(lenient.matches(&mismatched));
+
+    let mut strict = Filter::new().strict_chain();
+    assert!(strict.matches(&on_chain(Chain::Ethereum)));
+    assert!(!strict.matches(&mismatched));
+    assert_eq!(strict.stats().inconsistent_chain, 1);
+    assert!(strict.stats().chain.is_empty());
+}
+
+#[test]
+fn filters_headers_before_decoding() {
+    let header = |event: StreamEvent| -> StreamEventHeader {
+        serde_json::from_value(serde_json::to_value(event).unwrap()).unwrap()
+    };
+    let clock = MockClock::new(now());
+    let mut filter = Filter::new()
+        .max_age(StdDuration::from_secs(30))
+        .events([Event::ItemTransferred].into_iter().collect())
+        .exclude_collections(["spam"])
+        .chains([Chain::Ethereum])
+        .clock(clock);
+
+    assert!(filter.matches_header(&header(sent_at(now()))));
+    assert!(!filter.matches_header(&header(from_collection("spam"))));
+    assert!(!filter.matches_header(&header(sent_at(now() - Duration::minutes(1)))));
+    // Chain rules need the decoded event.
+    assert!(filter.matches_header(&header(on_chain(Chain::Polygon))));
+    assert!(!filter.matches(&on_chain(Chain::Polygon)));
+
+    let stats = filter.stats();
+    assert_eq!((stats.collection, stats.stale), (1, 1));
+    assert_eq!(stats.chain[&Chain::Polygon], 1);
+
+    let mut sold = Filter::new().events([Event::ItemSold].into_iter().collect());
+    assert!(!sold.matches_header(&header(sent_at(now()))));
+    assert_eq!(sold.stats().event_type, 1);
+}
+
+#[test]
+fn rejects_zero_value_transfers() {
+    let mut mint = sent_at(now());
+    transfer_data(&mut mint).from_account = Address::zero();
+
+    let mut free_sale: StreamEvent = serde_json::from_str(fixtures::ITEM_SOLD).unwrap();
+    match &mut free_sale.payload {
+        Payload::ItemSold(sale) => sale.sale_price = U256::zero(),
+        _ => unreachable!(),
+    }
+
+    let mut lenient = Filter::new();
+    assert!(lenient.matches(&mint));
+    assert!(lenient.matches(&free_sale));
+
+    let mut filter = Filter::new().exclude_zero_value_transfers();
+    assert!(filter.matches(&sent_at(now())));
+    assert!(filter.matches(&serde_json::from_str(fixtures::ITEM_SOLD).unwrap()));
+    assert!(!filter.matches(&mint));
+    assert!(!filter.matches(&free_sale));
+    assert_eq!(filter.stats().zero_value, 2);
+}
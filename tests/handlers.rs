@@ -0,0 +1,113 @@
+#![cfg(feature = "fixtures")]
+
+mod common;
+
+use common::{message, quantity, transfer};
+use opensea_stream::handlers::spawn_handlers;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::broadcast;
+
+const ITEMS: u64 = 8;
+const EVENTS_PER_ITEM: u64 = 4;
+
+#[tokio::test]
+async fn handles_items_in_order_and_concurrently() {
+    let (tx, rx) = broadcast::channel(64);
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let task = {
+        let handled = handled.clone();
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        spawn_handlers(rx, 4, move |event| {
+            let handled = handled.clone();
+            let in_flight = in_flight.clone();
+            let max_in_flight = max_in_flight.clone();
+            async move {
+                let running = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let item = event.payload.context().item.nft_id.id.as_u64();
+                handled.lock().unwrap().push((item, quantity(&event)));
+            }
+        })
+    };
+
+    for seq in 0..EVENTS_PER_ITEM {
+        for item in 0..ITEMS {
+            tx.send(message(transfer(item, seq))).unwrap();
+        }
+    }
+    drop(tx);
+    task.await.unwrap();
+
+    let handled = handled.lock().unwrap();
+    assert_eq!(handled.len() as u64, ITEMS * EVENTS_PER_ITEM);
+    for item in 0..ITEMS {
+        let order: Vec<_> = handled
+            .iter()
+            .filter(|(handled_item, _)| *handled_item == item)
+            .map(|(_, seq)| *seq)
+            .collect();
+        assert_eq!(order, (0..EVENTS_PER_ITEM).collect::<Vec<_>>());
+    }
+    assert!(max_in_flight.load(Ordering::SeqCst) > 1);
+}
+
+#[tokio::test]
+async fn resumes_handler_panics() {
+    let (tx, rx) = broadcast::channel(4);
+    let task = spawn_handlers(rx, 2, |_| async { panic!("handler failed") });
+
+    tx.send(message(transfer(0, 0))).unwrap();
+    drop(tx);
+
+    let error = task.await.unwrap_err();
+    assert!(error.is_panic());
+    assert_eq!(
+        error.into_panic().downcast_ref::<&str>(),
+        Some(&"handler failed")
+    );
+}
+
+#[tokio::test]
+async fn stops_dispatching_after_a_panic() {
+    let (tx, rx) = broadcast::channel(64);
+    let handled = Arc::new(AtomicUsize::new(0));
+
+    let task = {
+        let handled = handled.clone();
+        spawn_handlers(rx, 2, move |event| {
+            let handled = handled.clone();
+            async move {
+                if quantity(&event) == 0 {
+                    panic!("handler failed");
+                }
+                handled.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+    };
+
+    tx.send(message(transfer(0, 0))).unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // Events for every worker arrive after the panic, and none of them are handled.
+    // The item that panicked comes last, so the failed send to its worker is not what stops dispatch.
+    for item in (1..ITEMS).chain([0]) {
+        let _ = tx.send(message(transfer(item, 1)));
+    }
+    drop(tx);
+
+    assert!(task.await.unwrap_err().is_panic());
+    assert_eq!(handled.load(Ordering::SeqCst), 0);
+}
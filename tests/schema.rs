@@ -0,0 +1,168 @@
+#![cfg(feature = "fixtures")]
+
+use ethers_core::types::U256;
+use opensea_stream::{
+    fixtures,
+    schema::{DemandOrder, Payload, StreamEvent, StreamEventHeader, TraitValue},
+    Event,
+};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// [`fixtures::ITEM_TRANSFERRED`], as re-serialized by this crate.
+fn sample() -> Value {
+    let event: StreamEvent = serde_json::from_str(fixtures::ITEM_TRANSFERRED).unwrap();
+    serde_json::to_value(event).unwrap()
+}
+
+#[test]
+fn decodes_payload_before_event_type() {
+    let sample = sample();
+    let json = format!(
+        r#"{{"payload": {}, "sent_at": {}, "event_type": {}}}"#,
+        sample["payload"], sample["sent_at"], sample["event_type"]
+    );
+
+    let event: StreamEvent = serde_json::from_str(&json).unwrap();
+    assert!(matches!(event.payload, Payload::ItemTransferred(_)));
+    assert_eq!(serde_json::to_value(&event).unwrap(), sample);
+}
+
+#[test]
+fn reports_missing_fields() {
+    for field in ["event_type", "payload", "sent_at"] {
+        let mut json = sample();
+        json.as_object_mut().unwrap().remove(field);
+
+        let error = serde_json::from_value::<StreamEvent>(json).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("missing field `{}`", field),
+            "without {}",
+            field
+        );
+    }
+}
+
+/// Paths of every key in `value`, e.g. `.payload.item.nft_id`.
+fn keys(value: &Value, path: &str, paths: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = format!("{}.{}", path, key);
+                keys(value, &path, paths);
+                paths.insert(path);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                keys(value, &format!("{}[]", path), paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn round_trips_fixtures() {
+    for fixture in fixtures::ALL {
+        let event: StreamEvent = serde_json::from_str(fixture).unwrap();
+
+        // Re-serialized events decode back into the same event.
+        let json = serde_json::to_value(&event).unwrap();
+        let round_tripped: StreamEvent = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&round_tripped).unwrap(), json);
+
+        // They have the same keys as the original, except for trait keys outside the schema.
+        let (mut expected, mut actual) = (BTreeSet::new(), BTreeSet::new());
+        keys(&serde_json::from_str(fixture).unwrap(), "", &mut expected);
+        keys(&json, "", &mut actual);
+        expected.retain(|key| !key.ends_with("traits[].order"));
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn decodes_irregular_metadata() {
+    let event: StreamEvent =
+        serde_json::from_str(fixtures::ITEM_METADATA_UPDATED_IRREGULAR).unwrap();
+    let data = match event.payload {
+        Payload::ItemMetadataUpdated(data) => data,
+        _ => unreachable!(),
+    };
+    assert_eq!(data.image_url, None);
+    assert_eq!(data.external_url, None);
+    assert_eq!(data.metadata_url, None);
+
+    let traits = data.traits.unwrap();
+    assert_eq!(traits.len(), 2);
+    assert_eq!(traits[0].max_value, Some(10.into()));
+    assert_eq!(
+        traits[0].trait_count.as_ref().and_then(|n| n.as_f64()),
+        Some(1.5)
+    );
+    assert!(matches!(traits[1].value, Some(TraitValue::Other(_))));
+    assert_eq!(
+        traits[1].value.as_ref().unwrap().to_string(),
+        r#"["Red","Blue"]"#
+    );
+}
+
+#[test]
+fn keeps_unknown_events_in_headers_only() {
+    let mut json = sample();
+    json["event_type"] = "item_minted".into();
+
+    let header: StreamEventHeader = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(header.event_type, Event::Unknown("item_minted".to_owned()));
+    assert!(serde_json::from_value::<StreamEvent>(json).is_err());
+}
+
+/// Decodes `fixture` with its price and quantity replaced.
+fn with_quantity(fixture: &str, price: &str, price_field: &str, quantity: u64) -> Payload {
+    let mut json: Value = serde_json::from_str(fixture).unwrap();
+    json["payload"][price_field] = price.into();
+    json["payload"]["quantity"] = quantity.into();
+    serde_json::from_value::<StreamEvent>(json).unwrap().payload
+}
+
+#[test]
+fn divides_prices_by_quantity() {
+    // 0.9 ETH for 3 items, 0.3 ETH each.
+    let listing = match with_quantity(fixtures::ITEM_LISTED, "900000000000000000", "base_price", 3)
+    {
+        Payload::ItemListed(listing) => listing,
+        _ => unreachable!(),
+    };
+    assert_eq!(listing.unit_price(), Some(U256::exp10(17) * 3));
+    #[cfg(feature = "rust_decimal")]
+    assert_eq!(listing.unit_price_decimal(), Some(Decimal::new(3, 1)));
+
+    // Prices in the smallest unit are rounded down.
+    let sale = match with_quantity(fixtures::ITEM_SOLD, "10", "sale_price", 3) {
+        Payload::ItemSold(sale) => sale,
+        _ => unreachable!(),
+    };
+    assert_eq!(sale.unit_price(), Some(U256::from(3)));
+
+    let offer = match with_quantity(
+        fixtures::ITEM_RECEIVED_OFFER,
+        "900000000000000000",
+        "base_price",
+        3,
+    ) {
+        Payload::ItemReceivedOffer(offer) => DemandOrder::from(offer),
+        _ => unreachable!(),
+    };
+    assert_eq!(offer.unit_price(), Some(U256::exp10(17) * 3));
+    #[cfg(feature = "rust_decimal")]
+    assert_eq!(offer.unit_price_decimal(), Some(Decimal::new(3, 1)));
+
+    let free = match with_quantity(fixtures::ITEM_LISTED, "0", "base_price", 0) {
+        Payload::ItemListed(listing) => listing,
+        _ => unreachable!(),
+    };
+    assert_eq!(free.unit_price(), None);
+}